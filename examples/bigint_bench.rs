@@ -0,0 +1,253 @@
+#![warn(clippy::pedantic)]
+// Compares the limb-based BigInt against the previous decimal string implementation,
+// and Karatsuba multiplication against schoolbook multiplication on much larger values
+// Run with: cargo run --release --example bigint_bench
+use advent_of_rust_2022::BigInt;
+use std::error::Error;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const DIGITS: usize = 1000;
+const LARGE_DIGITS: usize = 10_000;
+const ITERATIONS: u32 = 200;
+const CROSS_CHECKS: usize = 200;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let a_str = random_digits(DIGITS, 0x1234_5678);
+    let b_str = random_digits(DIGITS, 0x8765_4321);
+
+    let a = a_str.parse::<BigInt>()?;
+    let b = b_str.parse::<BigInt>()?;
+    let divisor = BigInt::from(97u32);
+
+    // Make sure both implementations agree before timing them
+    assert_eq!(a.add(&b).to_string(), add_string_numbers(&a_str, &b_str));
+    assert_eq!(
+        a.multiply_by(&b).to_string(),
+        multiply_string_numbers(&a_str, &b_str)
+    );
+    assert_eq!(
+        a.divide_by(&divisor).to_string(),
+        divide_string_numbers(&a_str, "97")
+    );
+
+    println!("Benchmarking {DIGITS}-digit values over {ITERATIONS} iterations");
+    println!("{}", "-".repeat(60));
+
+    let labels = ("limbs", "strings");
+    compare(
+        "add",
+        labels,
+        || a.add(&b),
+        || add_string_numbers(&a_str, &b_str),
+    );
+    compare(
+        "multiply",
+        labels,
+        || a.multiply_by(&b),
+        || multiply_string_numbers(&a_str, &b_str),
+    );
+    compare(
+        "divide",
+        labels,
+        || a.divide_by(&divisor),
+        || divide_string_numbers(&a_str, "97"),
+    );
+
+    // Make sure Karatsuba agrees with schoolbook on operands of many (and mismatched) sizes
+    for check in 0..CROSS_CHECKS {
+        let seed = u64::try_from(check).unwrap();
+        let a = random_digits(1 + check * 37 % 3000, seed * 2 + 1).parse::<BigInt>()?;
+        let b = random_digits(1 + check * 53 % 3000, seed * 2 + 2).parse::<BigInt>()?;
+        let expected = from_limbs(&schoolbook_multiply(&to_limbs(&a), &to_limbs(&b)));
+        assert_eq!(a.multiply_by(&b), expected);
+        assert_eq!(
+            a.squared(),
+            from_limbs(&schoolbook_multiply(&to_limbs(&a), &to_limbs(&a)))
+        );
+    }
+
+    let a = random_digits(LARGE_DIGITS, 0x0bad_cafe).parse::<BigInt>()?;
+    let b = random_digits(LARGE_DIGITS, 0xdead_beef).parse::<BigInt>()?;
+    let (a_limbs, b_limbs) = (to_limbs(&a), to_limbs(&b));
+
+    println!();
+    println!("Benchmarking {LARGE_DIGITS}-digit values over {ITERATIONS} iterations");
+    println!("{}", "-".repeat(60));
+
+    let labels = ("karatsuba", "schoolbook");
+    compare(
+        "multiply",
+        labels,
+        || a.multiply_by(&b),
+        || schoolbook_multiply(&a_limbs, &b_limbs),
+    );
+    compare(
+        "square",
+        labels,
+        || a.squared(),
+        || schoolbook_multiply(&a_limbs, &a_limbs),
+    );
+    Ok(())
+}
+
+// Times both implementations and prints the speedup of the first one
+fn compare<F, S, A, B>(name: &str, labels: (&str, &str), fast: F, slow: S)
+where
+    F: Fn() -> A,
+    S: Fn() -> B,
+{
+    let fast_time = time(|| black_box(fast()));
+    let slow_time = time(|| black_box(slow()));
+
+    println!(
+        "{name: <10} {}: {: >10.2?}  {}: {: >10.2?}  speedup: {:.1}x",
+        labels.0,
+        fast_time / ITERATIONS,
+        labels.1,
+        slow_time / ITERATIONS,
+        slow_time.as_secs_f64() / fast_time.as_secs_f64()
+    );
+}
+
+fn time<F, T>(func: F) -> Duration
+where
+    F: Fn() -> T,
+{
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        black_box(func());
+    }
+    start.elapsed()
+}
+
+// Generates a pseudo-random number with the exact number of digits (no leading zero)
+fn random_digits(count: usize, mut seed: u64) -> String {
+    (0..count)
+        .map(|index| {
+            seed = seed
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+            let digit = (seed >> 33) % 10;
+            let digit = if index == 0 && digit == 0 { 1 } else { digit };
+            char::from_digit(u32::try_from(digit).unwrap(), 10).unwrap()
+        })
+        .collect()
+}
+
+// Gets the base 2^32 limbs of a non-negative value (least significant first) via hexadecimal
+fn to_limbs(value: &BigInt) -> Vec<u32> {
+    let hex = value.to_str_radix(16);
+    let padded = format!("{hex:0>width$}", width = hex.len().div_ceil(8) * 8);
+    padded
+        .as_bytes()
+        .rchunks(8)
+        .map(|chunk| u32::from_str_radix(std::str::from_utf8(chunk).unwrap(), 16).unwrap())
+        .collect()
+}
+
+fn from_limbs(limbs: &[u32]) -> BigInt {
+    let hex = limbs
+        .iter()
+        .rev()
+        .fold(String::new(), |hex, limb| hex + &format!("{limb:08x}"));
+    BigInt::from_str_radix(&hex, 16).unwrap()
+}
+
+// The schoolbook limb multiplication used before Karatsuba, kept here for comparison
+fn schoolbook_multiply(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = vec![0; a.len() + b.len()];
+
+    for (a_idx, &a_limb) in a.iter().enumerate() {
+        let mut carry = 0;
+
+        for (b_idx, &b_limb) in b.iter().enumerate() {
+            let product =
+                u64::from(a_limb) * u64::from(b_limb) + u64::from(result[a_idx + b_idx]) + carry;
+            result[a_idx + b_idx] = u32::try_from(product & u64::from(u32::MAX)).unwrap();
+            carry = product >> 32;
+        }
+
+        result[a_idx + b.len()] = u32::try_from(carry).unwrap();
+    }
+
+    result
+}
+
+// The previous decimal string implementations, kept here for comparison
+fn add_string_numbers(a: &str, b: &str) -> String {
+    let mut a: Vec<char> = a.chars().collect();
+    let mut b: Vec<char> = b.chars().collect();
+
+    let mut carry = 0;
+    let mut result = Vec::with_capacity(a.len() + b.len());
+
+    while !a.is_empty() || !b.is_empty() || carry > 0 {
+        let mut sum = carry;
+
+        if let Some(&digit) = a.last() {
+            sum += digit.to_digit(10).unwrap();
+            a.pop();
+        }
+
+        if let Some(&digit) = b.last() {
+            sum += digit.to_digit(10).unwrap();
+            b.pop();
+        }
+
+        carry = sum / 10;
+        result.push(char::from_digit(sum % 10, 10).unwrap());
+    }
+
+    result.iter().rev().collect()
+}
+
+fn multiply_string_numbers(a: &str, b: &str) -> String {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let mut result = vec![0; a.len() + b.len()];
+
+    for (a_idx, &a_char) in a.iter().rev().enumerate() {
+        for (b_idx, &b_char) in b.iter().rev().enumerate() {
+            let prod = a_char.to_digit(10).unwrap() * b_char.to_digit(10).unwrap();
+            let sum = prod + result[a_idx + b_idx];
+
+            result[a_idx + b_idx] = sum % 10;
+            result[a_idx + b_idx + 1] += sum / 10;
+        }
+    }
+
+    while let Some(&0) = result.last() {
+        result.pop();
+    }
+
+    result
+        .into_iter()
+        .rev()
+        .map(|d| char::from_digit(d, 10).unwrap())
+        .collect()
+}
+
+fn divide_string_numbers(dividend: &str, divisor: &str) -> String {
+    let mut result: Vec<char> = Vec::new();
+    let mut remainder: i128 = 0;
+    let divisor = divisor.parse::<i128>().unwrap();
+
+    for digit_char in dividend.chars() {
+        let digit = i128::from(digit_char.to_digit(10).unwrap());
+        remainder = remainder * 10 + digit;
+
+        let quotient_digit = remainder / divisor;
+        remainder %= divisor;
+
+        result.push(char::from_digit(u32::try_from(quotient_digit).unwrap(), 10).unwrap());
+    }
+
+    // Remove leading zeros
+    while result.len() > 1 && result[0] == '0' {
+        result.remove(0);
+    }
+
+    result.iter().collect()
+}
@@ -0,0 +1,100 @@
+#![warn(clippy::pedantic)]
+// Checks the BigInt powers, roots, factorials and binomials against u128 arithmetic,
+// then checks the identities they should satisfy for values far beyond the u128 range
+// Run with: cargo run --example bigint_math
+use advent_of_rust_2022::BigInt;
+
+const SAMPLES: usize = 2000;
+
+fn main() {
+    let mut next = xorshift(0x9e37_79b9_7f4a_7c15);
+
+    // Powers should match whenever the u128 result does not overflow
+    for _ in 0..SAMPLES {
+        let base = next() >> (next() % 128);
+        let exponent = u32::try_from(next() % 130).unwrap();
+        if let Some(expected) = base.checked_pow(exponent) {
+            assert_eq!(BigInt::from(base).pow(exponent), BigInt::from(expected));
+        }
+
+        let signed =
+            i128::try_from(base >> 1).unwrap() * if next().is_multiple_of(2) { 1 } else { -1 };
+        if let Some(expected) = signed.checked_pow(exponent) {
+            assert_eq!(BigInt::from(signed).pow(exponent), BigInt::from(expected));
+        }
+    }
+
+    // Roots should be the largest value whose power does not exceed the original
+    for _ in 0..SAMPLES {
+        let value = next() >> (next() % 128);
+        assert_eq!(BigInt::from(value).isqrt(), BigInt::from(value.isqrt()));
+
+        let n = u32::try_from(next() % 12).unwrap() + 1;
+        let root = u128::try_from(BigInt::from(value).nth_root(n)).unwrap();
+        assert!(root.pow(n) <= value);
+        assert!((root + 1).checked_pow(n).is_none_or(|above| above > value));
+    }
+
+    // Odd roots of negative values round towards zero
+    assert_eq!(BigInt::from(-28).nth_root(3), BigInt::from(-3));
+    assert_eq!(BigInt::from(-27).nth_root(3), BigInt::from(-3));
+
+    // Every factorial up to 34! fits within a u128
+    let mut factorial: u128 = 1;
+    for n in 0..=34 {
+        if n > 0 {
+            factorial *= u128::from(n);
+        }
+        assert_eq!(BigInt::factorial(n), BigInt::from(factorial));
+    }
+
+    // Build Pascal's triangle, where every coefficient fits within a u128 up to row 128
+    let mut row: Vec<u128> = vec![1];
+    for n in 0..=128 {
+        for k in 0..=n + 1 {
+            let expected = row.get(usize::try_from(k).unwrap()).copied().unwrap_or(0);
+            assert_eq!(BigInt::binomial(n, k), BigInt::from(expected));
+        }
+        row = (0..=row.len())
+            .map(|k| {
+                let left = if k == 0 { 0 } else { row[k - 1] };
+                left + row.get(k).copied().unwrap_or(0)
+            })
+            .collect();
+    }
+
+    // Beyond u128, the functions should still agree with one another
+    for n in [100, 250, 500] {
+        for k in [0, 1, 7, n / 3, n / 2, n] {
+            assert_eq!(
+                BigInt::binomial(n, k),
+                BigInt::factorial(n) / (BigInt::factorial(k) * BigInt::factorial(n - k))
+            );
+        }
+        assert_eq!(BigInt::factorial(n + 1), BigInt::factorial(n) * (n + 1));
+    }
+
+    for _ in 0..SAMPLES / 10 {
+        let value = BigInt::from(next()) * BigInt::from(next()) * BigInt::from(next()) + 2u8;
+        let n = u32::try_from(next() % 9).unwrap() + 2;
+        let power = value.pow(n);
+        assert_eq!(power.nth_root(n), value);
+        assert_eq!((&power - 1u8).nth_root(n), &value - 1u8);
+        assert_eq!(power.clone().pow(2), value.pow(n * 2));
+        assert_eq!(value.pow(2).isqrt(), value);
+        assert_eq!((value.pow(2) - 1u8).isqrt(), &value - 1u8);
+    }
+
+    println!("All powers, roots, factorials and binomials matched");
+}
+
+// Generates pseudo-random 128-bit values from two xorshift steps
+fn xorshift(mut state: u64) -> impl FnMut() -> u128 {
+    let mut step = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+    move || (u128::from(step()) << 64) | u128::from(step())
+}
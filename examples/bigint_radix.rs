@@ -0,0 +1,106 @@
+#![warn(clippy::pedantic)]
+// Round-trips BigInt values through every radix and a few balanced numeral systems
+// Run with: cargo run --example bigint_radix
+use advent_of_rust_2022::{BalancedRadix, BigInt, BigIntError};
+use std::error::Error;
+
+const SAMPLES: usize = 500;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let values = sample_values();
+
+    // Every value should survive formatting and parsing in every radix
+    for radix in 2..=36 {
+        for value in &values {
+            let formatted = value.to_str_radix(radix);
+            assert_eq!(&BigInt::from_str_radix(&formatted, radix)?, value);
+            assert_eq!(
+                BigInt::from_str_radix(&formatted.to_uppercase(), radix)?,
+                *value
+            );
+        }
+    }
+
+    // Values that fit in a primitive should match the standard library formatting
+    for (value, expected) in [
+        (255, "ff"),
+        (-255, "-ff"),
+        (0, "0"),
+        (i128::MAX, "7fffffffffffffffffffffffffffffff"),
+    ] {
+        assert_eq!(
+            value.to_string().parse::<BigInt>()?.to_str_radix(16),
+            expected
+        );
+    }
+    assert_eq!(BigInt::from_str_radix("-z", 36)?.to_string(), "-35");
+    assert_eq!(BigInt::from_str_radix("101", 2)?.to_string(), "5");
+    assert_eq!(
+        BigInt::from_str_radix("12", 2),
+        Err(BigIntError::InvalidDigit {
+            character: '2',
+            position: 1
+        })
+    );
+    assert_eq!(BigInt::from_str_radix("-", 8), Err(BigIntError::Empty));
+
+    // Balanced base 5, where `=` is -2 and `-` is -1
+    let snafu = BalancedRadix::new("=-012");
+    for (decimal, balanced) in [
+        ("0", "0"),
+        ("1", "1"),
+        ("3", "1="),
+        ("8", "2="),
+        ("2022", "1=11-2"),
+        ("12345", "1-0---0"),
+        ("314159265", "1121-1110-1=0"),
+        ("-3", "-2"),
+    ] {
+        let value = decimal.parse::<BigInt>()?;
+        assert_eq!(snafu.format(&value), balanced);
+        assert_eq!(snafu.parse(balanced)?, value);
+    }
+
+    // Balanced ternary (in two notations) and a much larger balanced base 35
+    for alphabet in ["-0+", "T01", "qponmlkjihgfedcba0123456789ABCDEFGH"] {
+        let balanced = BalancedRadix::new(alphabet);
+        for value in &values {
+            assert_eq!(&balanced.parse(&balanced.format(value))?, value);
+        }
+    }
+
+    println!(
+        "All {} values round-tripped through radix 2-36 and balanced systems",
+        values.len()
+    );
+    Ok(())
+}
+
+// Generates values of many different sizes and signs, including the limb boundaries
+fn sample_values() -> Vec<BigInt> {
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    let mut values: Vec<BigInt> = ["0", "1", "-1", "4294967295", "4294967296", "-4294967296"]
+        .iter()
+        .map(|s| s.parse().unwrap())
+        .collect();
+
+    for _ in 0..SAMPLES {
+        let digits = usize::try_from(next() % 80).unwrap() + 1;
+        let mut text: String = (0..digits)
+            .map(|_| char::from(b'0' + u8::try_from(next() % 10).unwrap()))
+            .collect();
+        if next() % 2 == 0 {
+            text.insert(0, '-');
+        }
+        values.push(text.parse().unwrap());
+    }
+
+    values
+}
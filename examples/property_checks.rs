@@ -0,0 +1,622 @@
+#![warn(clippy::pedantic)]
+// Property checks for the shared data structures, comparing each against a simpler model
+// Run with: cargo run --release --example property_checks
+use advent_of_rust_2022::{
+    beam_search, branch_and_bound, brent_cycle, check_property, find_path, floyd_cycle,
+    ArenaIntervalTree, ArenaLinkedList, BigInt, Cycle, CycleDetector, IntStrategy, Interval,
+    IntervalSet, IntervalTreeNode, Point, PropertyConfig, SearchProblem, SearchResult,
+    StringStrategy, VecStrategy,
+};
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::error::Error;
+
+fn main() -> Result<(), Box<dyn Error>> {
+    let config = PropertyConfig {
+        cases: 2000,
+        ..PropertyConfig::default()
+    };
+
+    check_shrinking(&config);
+    check_bigint(&config)?;
+    check_linked_list(&config)?;
+    check_find_path(&config)?;
+    check_interval_tree(&config)?;
+    check_interval_set(&config)?;
+    check_search(&config)?;
+    check_cycle(&config)?;
+
+    println!("All properties held over {} cases each", config.cases);
+    Ok(())
+}
+
+// A false property should shrink to a counterexample right on the boundary
+fn check_shrinking(config: &PropertyConfig) {
+    let strategy = (IntStrategy::new(0, u32::MAX), IntStrategy::new(0, u32::MAX));
+    let Err(failure) = check_property(config, &strategy, |&(a, b)| {
+        u64::from(a) + u64::from(b) <= 1000
+    }) else {
+        panic!("Expected the sum to exceed 1000 for some values");
+    };
+
+    let (a, b) = failure.shrunk;
+    assert_eq!(u64::from(a) + u64::from(b), 1001, "{failure}");
+    println!("shrinking: {failure}");
+}
+
+// BigInt arithmetic should match u128 and i128 whenever they do not overflow
+fn check_bigint(config: &PropertyConfig) -> Result<(), Box<dyn Error>> {
+    let unsigned = IntStrategy::new(0, u128::MAX);
+    check_property(config, &(unsigned, unsigned), |&(a, b)| {
+        let (big_a, big_b) = (BigInt::from(a), BigInt::from(b));
+        let matches = |big: BigInt, expected: Option<u128>| u128::try_from(big).ok() == expected;
+
+        matches(&big_a + &big_b, a.checked_add(b))
+            && matches(&big_a * &big_b, a.checked_mul(b))
+            && (b == 0 || matches(&big_a / &big_b, Some(a / b)))
+            && matches(&big_a - &big_b, if a >= b { Some(a - b) } else { None })
+            && (a < b) == (big_a < big_b)
+    })
+    .map_err(|failure| failure.to_string())?;
+
+    let signed = IntStrategy::new(i128::MIN, i128::MAX);
+    check_property(config, &(signed, signed), |&(a, b)| {
+        let (big_a, big_b) = (BigInt::from(a), BigInt::from(b));
+        let matches = |big: BigInt, expected: Option<i128>| i128::try_from(big).ok() == expected;
+
+        matches(&big_a - &big_b, a.checked_sub(b))
+            && (b == 0
+                || (matches(&big_a / &big_b, a.checked_div(b))
+                    && matches(&big_a % &big_b, a.checked_rem(b))))
+            && big_a.to_string() == a.to_string()
+    })
+    .map_err(|failure| failure.to_string())?;
+
+    // Parsing should ignore leading zeros, and agree with u128 for values that fit
+    let digits = StringStrategy::new("0123456789", 45);
+    check_property(config, &digits, |text| match text.parse::<BigInt>() {
+        Ok(value) => {
+            let trimmed = text.trim_start_matches('0');
+            let canonical = if trimmed.is_empty() { "0" } else { trimmed };
+            value.to_string() == canonical
+                && text.parse::<u128>().ok() == u128::try_from(&value).ok()
+        }
+        Err(_) => text.is_empty(),
+    })
+    .map_err(|failure| failure.to_string())?;
+
+    println!("BigInt matches u128 and i128");
+    Ok(())
+}
+
+// Applies a sequence of operations to both the list and a vector of (index, value) pairs,
+// where the value also picks which existing nodes the operation targets
+fn check_linked_list(config: &PropertyConfig) -> Result<(), Box<dyn Error>> {
+    let operations = VecStrategy::new(
+        (IntStrategy::new(0usize, 5), IntStrategy::new(0usize, 1000)),
+        60,
+    );
+
+    check_property(config, &operations, |operations| {
+        let mut list = ArenaLinkedList::new();
+        let mut model: Vec<(usize, usize)> = Vec::new();
+        let mut freed: Vec<usize> = Vec::new();
+        let mut slots = 0;
+
+        for &(kind, value) in operations {
+            let position = value % model.len().max(1);
+            let other = value / 7 % model.len().max(1);
+
+            let valid = match (kind, model.get(position).copied()) {
+                (0, _) => {
+                    let popped = model.pop();
+                    if let Some((index, _)) = popped {
+                        freed.push(index);
+                    }
+                    list.pop() == popped.map(|(_, value)| value)
+                }
+                (_, None) | (1, _) => {
+                    let index = list.push(value);
+                    model.push((index, value));
+                    allocated(&mut freed, &mut slots, index)
+                }
+                (2, Some((target, _))) => {
+                    let index = list.insert_after(target, value).unwrap();
+                    model.insert(position + 1, (index, value));
+                    allocated(&mut freed, &mut slots, index)
+                }
+                (3, Some((target, _))) => {
+                    let index = list.insert_before(target, value).unwrap();
+                    model.insert(position, (index, value));
+                    allocated(&mut freed, &mut slots, index)
+                }
+                (4, Some((index, expected))) => {
+                    model.remove(position);
+                    freed.push(index);
+                    list.remove(index) == Some(expected) && list.remove(index).is_none()
+                }
+                (_, Some(node)) => {
+                    let target = model[other].0;
+                    if node.0 != target {
+                        model.remove(position);
+                        let target_position = model.iter().position(|&(index, _)| index == target);
+                        model.insert(target_position.unwrap() + 1, node);
+                    }
+                    list.move_after(node.0, target)
+                }
+            };
+
+            let mut forwards = Vec::new();
+            list.traverse(|&value| forwards.push(value));
+            let mut backwards = Vec::new();
+            list.traverse_rev(|&value| backwards.push(value));
+            backwards.reverse();
+
+            // Every remaining node must still be found at the same index
+            let stable = model.iter().all(|&(index, value)| {
+                list.node(index)
+                    .is_some_and(|node| node.index == index && node.value == value)
+            });
+
+            if !valid
+                || !stable
+                || list.len() != model.len()
+                || list.first() != model.first().map(|(_, value)| value)
+                || list.last() != model.last().map(|(_, value)| value)
+                || forwards != backwards
+                || !forwards.iter().eq(model.iter().map(|(_, value)| value))
+            {
+                return false;
+            }
+        }
+        true
+    })
+    .map_err(|failure| failure.to_string())?;
+
+    println!("ArenaLinkedList matches a vector model");
+    Ok(())
+}
+
+// New nodes must reuse a removed slot when there is one, and only extend the arena otherwise
+fn allocated(freed: &mut Vec<usize>, slots: &mut usize, index: usize) -> bool {
+    if let Some(position) = freed.iter().position(|&slot| slot == index) {
+        freed.swap_remove(position);
+        true
+    } else {
+        *slots += 1;
+        freed.is_empty() && index == *slots - 1
+    }
+}
+
+// With a uniform cost, the A* path should be exactly as long as a breadth-first search
+fn check_find_path(config: &PropertyConfig) -> Result<(), Box<dyn Error>> {
+    // Each cell is a wall when its value is 3, so shrinking tends to remove walls
+    let grids = (
+        IntStrategy::new(1usize, 8),
+        VecStrategy::new(IntStrategy::new(0u8, 3), 64),
+    );
+
+    check_property(config, &grids, |(width, cells)| {
+        let walls: HashSet<Point> = cells
+            .iter()
+            .enumerate()
+            .filter(|&(_, &cell)| cell == 3)
+            .filter_map(|(index, _)| Point::from_grid(index % width, index / width))
+            .collect();
+        let height = cells.len().div_ceil(*width).max(1);
+        let open = |point: &Point| {
+            point
+                .to_grid()
+                .is_some_and(|(x, y)| x < *width && y < height && !walls.contains(point))
+        };
+
+        let start = Point::new(0, 0);
+        let goal = Point::from_grid(width - 1, height - 1).unwrap();
+        let path = find_path(&start, &goal, |_, to| open(to).then_some(1));
+        let expected = breadth_first_distance(start, goal, open);
+
+        // The path must also be a connected walk through open cells
+        let valid = path.as_ref().is_none_or(|path| {
+            path.windows(2)
+                .all(|pair| pair[0].neighbors4().contains(&pair[1]) && open(&pair[1]))
+        });
+        valid && path.map(|path| path.len() - 1) == expected
+    })
+    .map_err(|failure| failure.to_string())?;
+
+    println!("find_path matches breadth-first search");
+    Ok(())
+}
+
+// Like A*, only moving into a cell has a cost, so the start is always allowed even if it is a wall
+fn breadth_first_distance<F>(start: Point, goal: Point, open: F) -> Option<usize>
+where
+    F: Fn(&Point) -> bool,
+{
+    let mut visited = HashSet::from([start]);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((point, distance)) = queue.pop_front() {
+        if point == goal {
+            return Some(distance);
+        }
+        for neighbor in point.neighbors4() {
+            if open(&neighbor) && visited.insert(neighbor) {
+                queue.push_back((neighbor, distance + 1));
+            }
+        }
+    }
+    None
+}
+
+// Applies a sequence of operations to the tree and a vector of slots, where 0 removes,
+// 1 finds the intervals overlapping a range, and anything else inserts
+fn check_interval_tree(config: &PropertyConfig) -> Result<(), Box<dyn Error>> {
+    let operations = VecStrategy::new(
+        (
+            IntStrategy::new(0u8, 3),
+            IntStrategy::new(-40i32, 40),
+            IntStrategy::new(-40i32, 40),
+        ),
+        80,
+    );
+
+    check_property(config, &operations, |operations| {
+        let mut tree = ArenaIntervalTree::new();
+        let mut slots: Vec<Option<(i32, i32, usize)>> = Vec::new();
+
+        for (step, &(kind, a, b)) in operations.iter().enumerate() {
+            match kind {
+                0 => {
+                    // The index may point at an empty or unused slot, which should do nothing
+                    let index = usize::try_from(a.unsigned_abs()).unwrap() % (slots.len() + 1);
+                    let expected = slots.get_mut(index).and_then(Option::take);
+                    if tree.remove(index) != expected.map(|(_, _, value)| value) {
+                        return false;
+                    }
+                }
+                1 => {
+                    if sorted_indices(&tree.find_overlapping(a, b)) != overlapping(&slots, a, b)
+                        || sorted_indices(&tree.stab(a)) != overlapping(&slots, a, a)
+                    {
+                        return false;
+                    }
+                }
+                _ => {
+                    // The new node must fill an empty slot, or be added after the last one
+                    let index = tree.insert(a, b, step);
+                    let interval = Some((a.min(b), a.max(b), step));
+                    if index == slots.len() {
+                        slots.push(interval);
+                    } else if slots.get(index) == Some(&None) {
+                        slots[index] = interval;
+                    } else {
+                        return false;
+                    }
+                }
+            }
+
+            let mut visited = Vec::new();
+            tree.traverse(|node| visited.push((node.start, node.end, node.value)));
+            let in_order = visited.is_sorted_by_key(|&(start, _, _)| start);
+            visited.sort_unstable();
+            let mut expected: Vec<_> = slots.iter().flatten().copied().collect();
+            expected.sort_unstable();
+
+            let nodes_match = slots.iter().enumerate().all(|(index, slot)| {
+                tree.get(index)
+                    .map(|node| (node.start, node.end, node.value))
+                    == *slot
+            });
+            if !in_order || visited != expected || tree.len() != expected.len() || !nodes_match {
+                return false;
+            }
+        }
+        true
+    })
+    .map_err(|failure| failure.to_string())?;
+
+    println!("ArenaIntervalTree matches a linear scan");
+    Ok(())
+}
+
+// The tree returns nodes in traversal order, so sort them to compare with the linear scan
+fn sorted_indices<K, V>(nodes: &[&IntervalTreeNode<K, V>]) -> Vec<usize> {
+    let mut indices: Vec<usize> = nodes.iter().map(|node| node.index).collect();
+    indices.sort_unstable();
+    indices
+}
+
+// Gets the index of every interval that shares a value with the inclusive range
+fn overlapping(slots: &[Option<(i32, i32, usize)>], a: i32, b: i32) -> Vec<usize> {
+    let (start, end) = (a.min(b), a.max(b));
+    slots
+        .iter()
+        .enumerate()
+        .filter(|(_, slot)| slot.is_some_and(|(s, e, _)| s <= end && start <= e))
+        .map(|(index, _)| index)
+        .collect()
+}
+
+// Interval endpoints are drawn from clusters of values at the bottom, middle and top of the
+// i32 range, so every operation runs close to the edges where off by one errors would overflow
+const CLUSTERS: [i32; 3] = [i32::MIN, -4, i32::MAX - 7];
+const CLUSTER_SIZE: usize = 8;
+const ENDPOINTS: usize = CLUSTERS.len() * CLUSTER_SIZE;
+
+fn endpoint(index: usize) -> i32 {
+    CLUSTERS[index / CLUSTER_SIZE] + i32::try_from(index % CLUSTER_SIZE).unwrap()
+}
+
+// Splits every i32 into pieces that are always covered as a whole: piece 2n is the endpoint n,
+// and piece 2n + 1 is the (possibly empty) range between endpoints n and n + 1
+fn piece(index: usize) -> Option<Interval> {
+    let below = endpoint(index / 2);
+    if index.is_multiple_of(2) {
+        Some(Interval::single(below))
+    } else {
+        let above = endpoint(index / 2 + 1);
+        (above - below > 1).then(|| Interval::new(below + 1, above - 1))
+    }
+}
+
+// Joins the pieces that match the filter into the sorted, non-touching intervals IntervalSet uses
+fn join_pieces<F>(pieces: std::ops::RangeInclusive<usize>, filter: F) -> Vec<Interval>
+where
+    F: Fn(usize) -> bool,
+{
+    let mut joined: Vec<Interval> = Vec::new();
+    let mut extend = false;
+    for index in pieces {
+        let Some(interval) = piece(index) else {
+            continue;
+        };
+        if !filter(index) {
+            extend = false;
+        } else if extend {
+            joined.last_mut().unwrap().end = interval.end;
+        } else {
+            joined.push(interval);
+            extend = true;
+        }
+    }
+    joined
+}
+
+// Applies a sequence of operations to the set and a bitset of pieces, where 0 subtracts,
+// 1 finds the gaps within the bounds, and anything else inserts
+fn check_interval_set(config: &PropertyConfig) -> Result<(), Box<dyn Error>> {
+    let operations = VecStrategy::new(
+        (
+            IntStrategy::new(0u8, 3),
+            IntStrategy::new(0, ENDPOINTS - 1),
+            IntStrategy::new(0, ENDPOINTS - 1),
+        ),
+        30,
+    );
+    let all_pieces = 0..=2 * (ENDPOINTS - 1);
+
+    check_property(config, &operations, |operations| {
+        let mut set = IntervalSet::new();
+        let mut covered = [false; 2 * ENDPOINTS - 1];
+        let mut inserted = Vec::new();
+        let mut only_inserts = IntervalSet::new();
+
+        for &(kind, a, b) in operations {
+            let interval = Interval::new(endpoint(a), endpoint(b));
+            let pieces = 2 * a.min(b)..=2 * a.max(b);
+
+            match kind {
+                0 => {
+                    set.subtract(&interval);
+                    covered[pieces].fill(false);
+                }
+                1 => {
+                    if set.gaps(&interval) != join_pieces(pieces, |index| !covered[index]) {
+                        return false;
+                    }
+                }
+                _ => {
+                    set.insert(interval);
+                    covered[pieces].fill(true);
+                    inserted.push(interval);
+                    only_inserts.insert(interval);
+                }
+            }
+
+            let contains_endpoints =
+                (0..ENDPOINTS).all(|index| set.contains(endpoint(index)) == covered[2 * index]);
+            if set.intervals() != join_pieces(all_pieces.clone(), |index| covered[index])
+                || !contains_endpoints
+            {
+                return false;
+            }
+        }
+
+        // Gaps over the whole range must not overflow past either end, and collecting the
+        // intervals in one go must give the same set as inserting them one at a time
+        let everything = Interval::new(i32::MIN, i32::MAX);
+        set.gaps(&everything) == join_pieces(all_pieces.clone(), |index| !covered[index])
+            && inserted.into_iter().collect::<IntervalSet>() == only_inserts
+    })
+    .map_err(|failure| failure.to_string())?;
+
+    println!("IntervalSet matches a bitset across the i32 range");
+    Ok(())
+}
+
+// A 0/1 knapsack, choosing which items to take (in order) without exceeding the capacity
+struct Knapsack {
+    items: Vec<(u32, u32)>,
+    capacity: u32,
+}
+
+// Tracks the next item to consider, along with the weight and value taken so far
+#[derive(Debug, Clone)]
+struct Packing {
+    next: usize,
+    weight: u32,
+    value: u32,
+    taken: Vec<usize>,
+}
+
+impl SearchProblem for Knapsack {
+    type State = Packing;
+    type Score = u32;
+    type Key = (usize, u32);
+
+    fn successors(&self, state: &Packing) -> Vec<Packing> {
+        let Some(&(weight, value)) = self.items.get(state.next) else {
+            return Vec::new();
+        };
+
+        let skip = Packing {
+            next: state.next + 1,
+            ..state.clone()
+        };
+        let mut successors = vec![skip.clone()];
+        if state.weight + weight <= self.capacity {
+            let mut take = skip;
+            take.weight += weight;
+            take.value += value;
+            take.taken.push(state.next);
+            successors.push(take);
+        }
+        successors
+    }
+
+    fn score(&self, state: &Packing) -> u32 {
+        state.value
+    }
+
+    fn upper_bound(&self, state: &Packing) -> u32 {
+        state.value
+            + self.items[state.next..]
+                .iter()
+                .map(|&(_, value)| value)
+                .sum::<u32>()
+    }
+
+    // With the same items left and the same weight used, only the value matters
+    fn key(&self, state: &Packing) -> Option<(usize, u32)> {
+        Some((state.next, state.weight))
+    }
+}
+
+// Branch and bound should always find the best packing, and beam search should never claim a
+// better one (finding it too when the beam is wide enough to keep every state)
+fn check_search(config: &PropertyConfig) -> Result<(), Box<dyn Error>> {
+    let problems = (
+        VecStrategy::new((IntStrategy::new(1u32, 20), IntStrategy::new(0u32, 30)), 10),
+        IntStrategy::new(0u32, 60),
+        IntStrategy::new(1usize, 8),
+    );
+
+    check_property(config, &problems, |(items, capacity, width)| {
+        let problem = Knapsack {
+            items: items.clone(),
+            capacity: *capacity,
+        };
+        let start = Packing {
+            next: 0,
+            weight: 0,
+            value: 0,
+            taken: Vec::new(),
+        };
+
+        // Try every subset of the items
+        let best = (0..1u32 << items.len())
+            .filter_map(|subset| {
+                let chosen = items
+                    .iter()
+                    .enumerate()
+                    .filter(|&(index, _)| subset & (1 << index) != 0);
+                let (weight, value) = chosen.fold((0, 0), |(w, v), (_, &(weight, value))| {
+                    (w + weight, v + value)
+                });
+                (weight <= *capacity).then_some(value)
+            })
+            .max();
+
+        // The reported state must actually be a valid packing with the reported score
+        let consistent = |state: &Packing, score: u32| {
+            let (weight, value) = state.taken.iter().fold((0, 0), |(w, v), &index| {
+                (w + items[index].0, v + items[index].1)
+            });
+            weight == state.weight && weight <= *capacity && value == score
+        };
+        let valid = |result: &SearchResult<Packing, u32>| {
+            result
+                .best
+                .as_ref()
+                .is_some_and(|(state, score)| consistent(state, *score))
+        };
+
+        let exact = branch_and_bound(&problem, start.clone());
+        let narrow = beam_search(&problem, start.clone(), *width);
+        let wide = beam_search(&problem, start, 1 << items.len());
+
+        valid(&exact)
+            && valid(&narrow)
+            && valid(&wide)
+            && exact.best_score() == best
+            && wide.best_score() == best
+            && narrow.best_score() <= best
+    })
+    .map_err(|failure| failure.to_string())?;
+
+    println!("branch_and_bound and beam_search match brute force");
+    Ok(())
+}
+
+// Follows a random function from a small set of states to itself, which must eventually repeat
+fn check_cycle(config: &PropertyConfig) -> Result<(), Box<dyn Error>> {
+    let graphs = (
+        IntStrategy::new(0usize, 15),
+        VecStrategy::new(IntStrategy::new(0usize, 15), 16),
+    );
+
+    check_property(config, &graphs, |(start, targets)| {
+        if targets.is_empty() {
+            return true;
+        }
+        let step = |&state: &usize| targets[state] % targets.len();
+        let start = start % targets.len();
+
+        // Walk until a state repeats, which gives the start and length of the cycle directly
+        let mut seen = HashMap::new();
+        let mut state = start;
+        while !seen.contains_key(&state) {
+            seen.insert(state, seen.len());
+            state = step(&state);
+        }
+        let expected = Cycle::new(seen[&state], seen.len() - seen[&state]);
+
+        // Feed the detector one step at a time, recording the total of the states left so far
+        let mut detector = CycleDetector::new();
+        let (mut state, mut total) = (start, 0);
+        while detector.observe(state, total).is_none() {
+            total += i64::try_from(state).unwrap() + 1;
+            state = step(&state);
+        }
+
+        // Simulate far enough to cover several laps of the cycle
+        let mut simulated = vec![0];
+        let mut state = start;
+        for _ in 0..100 {
+            simulated.push(simulated.last().unwrap() + i64::try_from(state).unwrap() + 1);
+            state = step(&state);
+        }
+
+        floyd_cycle(&start, step, |&state| state) == expected
+            && brent_cycle(&start, step, |&state| state) == expected
+            && detector.cycle() == Some(expected)
+            && simulated
+                .iter()
+                .enumerate()
+                .all(|(steps, &total)| detector.extrapolate(steps) == Some(total))
+    })
+    .map_err(|failure| failure.to_string())?;
+
+    println!("Cycle detection matches brute force");
+    Ok(())
+}
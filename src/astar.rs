@@ -1,28 +1,6 @@
+use crate::Point;
 use std::cmp::Ordering;
 use std::collections::{BinaryHeap, HashMap};
-use std::fmt::{Display, Formatter};
-
-// Represents each cardinal direction as a x/y tile offset
-const DIRECTIONS: [(i32, i32); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
-
-#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
-pub struct Point {
-    pub x: i32,
-    pub y: i32,
-}
-
-impl Point {
-    #[must_use]
-    pub fn new(x: i32, y: i32) -> Self {
-        Self { x, y }
-    }
-}
-
-impl Display for Point {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}, {}", self.x, self.y)
-    }
-}
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct PathNode {
@@ -93,9 +71,7 @@ where
         }
 
         // Continue searching in all directions
-        for offset in &DIRECTIONS {
-            let neighbor = Point::new(current_point.x + offset.0, current_point.y + offset.1);
-
+        for neighbor in current_point.neighbors4() {
             if let Some(tile_cost) = cost_func(&current_point, &neighbor) {
                 let g_score = g_scores[&current_point] + tile_cost;
 
@@ -1,174 +1,1191 @@
+use crate::Integer;
+use std::cmp::Ordering;
+use std::error::Error;
 use std::fmt::{Display, Formatter};
-use std::ops::{Add, Div, Mul, Sub};
+use std::iter::{Product, Sum};
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
 use std::str::FromStr;
 
-pub enum BigIntParseError {
-    InvalidCharacter,
+// Each limb holds 32 bits of the value, with the least significant limb first
+// Products of two limbs (plus carries) always fit within a u64
+const LIMB_BITS: u32 = 32;
+
+// Below this many limbs (in the shorter operand), schoolbook multiplication is faster than Karatsuba
+const KARATSUBA_THRESHOLD: usize = 48;
+
+// Radix conversions group digits into chunks that fit within a single limb
+const MIN_RADIX: u32 = 2;
+const MAX_RADIX: u32 = 36;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum BigIntError {
+    // There were no digits to parse
+    Empty,
+    // The character at the byte position is not a valid digit for the radix or alphabet
+    InvalidDigit { character: char, position: usize },
+    DivisionByZero,
+    // The value does not fit within the target type
+    Overflow,
+}
+
+impl Display for BigIntError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BigIntError::Empty => write!(f, "Cannot parse an integer without any digits"),
+            BigIntError::InvalidDigit {
+                character,
+                position,
+            } => write!(f, "Invalid digit {character:?} at position {position}"),
+            BigIntError::DivisionByZero => write!(f, "Attempted to divide by zero"),
+            BigIntError::Overflow => write!(f, "Value is out of range for the target type"),
+        }
+    }
 }
 
-#[derive(Debug, Clone)]
+impl Error for BigIntError {}
+
+// Stored as sign and magnitude, where zero is never negative
+// Every constructor keeps the value in this canonical form, so the derived equality and hash
+// compare values numerically
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
 pub struct BigInt {
-    value: String,
+    // Never contains trailing (most significant) zero limbs, so zero is an empty vector
+    limbs: Vec<u32>,
+    negative: bool,
 }
 
 impl BigInt {
-    pub fn from_value<T>(value: &T) -> Self
-    where
-        T: Add + Sub + Mul + Div + Display,
-    {
-        Self {
-            value: value.to_string(),
-        }
+    #[must_use]
+    pub fn add(&self, other: &Self) -> Self {
+        add_signed(&self.limbs, self.negative, &other.limbs, other.negative)
     }
 
     #[must_use]
-    pub fn add(&self, other: &Self) -> Self {
-        let sum = add_string_numbers(&self.value, &other.value);
-        BigInt { value: sum }
+    pub fn subtract(&self, other: &Self) -> Self {
+        add_signed(&self.limbs, self.negative, &other.limbs, !other.negative)
     }
 
     #[must_use]
     pub fn multiply_by(&self, other: &Self) -> Self {
-        let product = multiply_string_numbers(&self.value, &other.value);
-        BigInt { value: product }
+        Self::from_parts(
+            multiply_limbs(&self.limbs, &other.limbs),
+            self.negative != other.negative,
+        )
     }
 
     #[must_use]
     pub fn squared(&self) -> Self {
-        let square = multiply_string_numbers(&self.value, &self.value);
-        BigInt { value: square }
+        Self::from_limbs(square_limbs(&self.limbs))
+    }
+
+    // Raises the value to the power by repeated squaring, so it needs only O(log n) multiplications
+    #[must_use]
+    pub fn pow(&self, exponent: u32) -> Self {
+        let mut result = Self::from(1u8);
+        let mut base = self.clone();
+        let mut exponent = exponent;
+
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                result = &result * &base;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = base.squared();
+            }
+        }
+
+        result
+    }
+
+    // Gets the largest integer whose square is not greater than the value
+    #[must_use]
+    pub fn isqrt(&self) -> Self {
+        self.nth_root(2)
+    }
+
+    // Gets the largest integer whose n-th power is not greater than the value
+    // Odd roots of negative values are also allowed, which round towards zero
+    #[must_use]
+    pub fn nth_root(&self, n: u32) -> Self {
+        assert!(n > 0, "attempt to take the zeroth root");
+        assert!(
+            !self.negative || n % 2 == 1,
+            "attempt to take an even root of a negative number"
+        );
+
+        if self.negative {
+            return -self.abs().nth_root(n);
+        }
+        if self.is_zero() || n == 1 {
+            return self.clone();
+        }
+
+        // The value is below 2^n, and 2^n is the smallest power with a root of 2
+        if self.bits() <= u64::from(n) {
+            return Self::from(1u8);
+        }
+
+        // Newton's method converges downwards from any starting point above the root,
+        // so start from the power of two just above it
+        let divisor = BigInt::from(n);
+        let mut root = Self::power_of_two(self.bits().div_ceil(u64::from(n)));
+        loop {
+            let next = (&root * (n - 1) + self / root.pow(n - 1)) / &divisor;
+            if next >= root {
+                return root;
+            }
+            root = next;
+        }
+    }
+
+    // Gets the product of every integer from 1 to n
+    #[must_use]
+    pub fn factorial(n: u32) -> Self {
+        product_range(1, n)
+    }
+
+    // Gets the number of ways to choose k items from n, without regard to order
+    #[must_use]
+    pub fn binomial(n: u32, k: u32) -> Self {
+        if k > n {
+            return Self::default();
+        }
+
+        // Each step is the previous coefficient times a whole number, so the division is exact
+        let k = k.min(n - k);
+        (0..k).fold(Self::from(1u8), |result, i| result * (n - i) / (i + 1))
     }
 
     #[must_use]
     pub fn divide_by(&self, other: &BigInt) -> Self {
-        let quotient = divide_string_numbers(&self.value, &other.value);
-        BigInt {
-            value: quotient.unwrap(),
+        self.div_rem(other).0
+    }
+
+    // Divides by the other value, returning both the quotient and remainder
+    // Like the primitive types, the quotient is truncated towards zero
+    // and the remainder has the same sign as the dividend
+    #[must_use]
+    pub fn div_rem(&self, other: &BigInt) -> (Self, Self) {
+        self.checked_div_rem(other)
+            .unwrap_or_else(|| panic!("attempt to divide by zero"))
+    }
+
+    // Divides by the other value, returning the quotient and remainder (or None if dividing by zero)
+    #[must_use]
+    pub fn checked_div_rem(&self, other: &BigInt) -> Option<(Self, Self)> {
+        if other.is_zero() {
+            return None;
         }
+
+        let (quotient, remainder) = divide_limbs(&self.limbs, &other.limbs);
+        Some((
+            Self::from_parts(quotient, self.negative != other.negative),
+            Self::from_parts(remainder, self.negative),
+        ))
+    }
+
+    // Divides by the other value, or None if dividing by zero
+    #[must_use]
+    pub fn checked_div(&self, other: &BigInt) -> Option<Self> {
+        self.checked_div_rem(other).map(|(quotient, _)| quotient)
+    }
+
+    // Gets the remainder after dividing by the other value, or None if dividing by zero
+    #[must_use]
+    pub fn checked_rem(&self, other: &BigInt) -> Option<Self> {
+        self.checked_div_rem(other).map(|(_, remainder)| remainder)
+    }
+
+    // Gets the remainder after dividing by the other value
+    #[must_use]
+    pub fn remainder_by(&self, other: &BigInt) -> Self {
+        self.div_rem(other).1
+    }
+
+    // Determines if the value is evenly divisible by the divisor
+    pub fn divisible_by(&self, divisor: &BigInt) -> Result<bool, BigIntError> {
+        self.checked_rem(divisor)
+            .map(|remainder| remainder.is_zero())
+            .ok_or(BigIntError::DivisionByZero)
     }
 
-    pub fn divisible_by(&self, divisor: u32) -> Result<bool, String> {
-        if divisor == 0 {
-            return Ok(false);
+    // Gets the absolute value
+    #[must_use]
+    pub fn abs(&self) -> Self {
+        Self {
+            limbs: self.limbs.clone(),
+            negative: false,
         }
+    }
 
-        let mut remainder = 0;
-        for c in self.value.chars() {
-            if let Some(digit) = c.to_digit(10) {
-                remainder = (remainder * 10 + digit) % divisor;
-            } else {
-                return Err("Invalid digit".into());
+    // Gets -1, 0 or 1 depending on the sign of the value
+    #[must_use]
+    pub fn signum(&self) -> Self {
+        if self.is_zero() {
+            Self::default()
+        } else {
+            Self::from_parts(vec![1], self.negative)
+        }
+    }
+
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        self.limbs.is_empty()
+    }
+
+    #[must_use]
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    #[must_use]
+    pub fn is_positive(&self) -> bool {
+        !self.negative && !self.is_zero()
+    }
+
+    // Gets the number of bits needed to store the magnitude (zero for zero)
+    #[must_use]
+    pub fn bits(&self) -> u64 {
+        self.limbs.last().map_or(0, |&top| {
+            (self.limbs.len() as u64 - 1) * u64::from(LIMB_BITS)
+                + u64::from(LIMB_BITS - top.leading_zeros())
+        })
+    }
+
+    // Gets the closest floating point value, which is infinite if the value is too large
+    #[must_use]
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    pub fn to_f64(&self) -> f64 {
+        // Only the top 64 bits are converted, so the value is rounded just once; any lower bits
+        // are folded into the lowest of them, which is enough to round a near-tie correctly
+        let shift = self.bits().saturating_sub(64);
+        let (index, offset) = (
+            (shift / u64::from(LIMB_BITS)) as usize,
+            (shift % u64::from(LIMB_BITS)) as u32,
+        );
+
+        let window = self.limbs[index..]
+            .iter()
+            .take(3)
+            .rev()
+            .fold(0u128, |window, &limb| {
+                (window << LIMB_BITS) | u128::from(limb)
+            });
+        let sticky = self.limbs[..index].iter().any(|&limb| limb != 0)
+            || self
+                .limbs
+                .get(index)
+                .is_some_and(|&limb| limb & ((1 << offset) - 1) != 0);
+        let top = ((window >> offset) as u64) | u64::from(sticky);
+
+        let magnitude = top as f64 * 2f64.powi(i32::try_from(shift).unwrap_or(i32::MAX));
+        if self.negative {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+
+    // Creates the non-negative value from limbs, removing any trailing zero limbs
+    fn from_limbs(limbs: Vec<u32>) -> Self {
+        Self::from_parts(limbs, false)
+    }
+
+    // Creates the value from limbs and sign, ensuring zero is never negative
+    fn from_parts(mut limbs: Vec<u32>, negative: bool) -> Self {
+        trim_limbs(&mut limbs);
+        let negative = negative && !limbs.is_empty();
+        Self { limbs, negative }
+    }
+
+    // Creates the value 2^exponent
+    fn power_of_two(exponent: u64) -> Self {
+        let limb = usize::try_from(exponent / u64::from(LIMB_BITS)).unwrap();
+        let mut limbs = vec![0; limb + 1];
+        limbs[limb] = 1 << (exponent % u64::from(LIMB_BITS));
+        Self::from_limbs(limbs)
+    }
+
+    #[allow(clippy::cast_possible_truncation)]
+    fn from_u128(mut value: u128) -> Self {
+        let mut limbs = Vec::with_capacity(4);
+        while value > 0 {
+            limbs.push(value as u32);
+            value >>= LIMB_BITS;
+        }
+        Self::from_limbs(limbs)
+    }
+
+    fn from_i128(value: i128) -> Self {
+        let mut result = Self::from_u128(value.unsigned_abs());
+        result.negative = value < 0;
+        result
+    }
+
+    // Gets the value as a u128, or None if it is negative or too large
+    fn to_u128(&self) -> Option<u128> {
+        if self.negative || self.limbs.len() > 4 {
+            return None;
+        }
+        Some(
+            self.limbs
+                .iter()
+                .rev()
+                .fold(0, |value, &limb| (value << LIMB_BITS) | u128::from(limb)),
+        )
+    }
+
+    // Gets the value as an i128, or None if it is too large in either direction
+    fn to_i128(&self) -> Option<i128> {
+        let magnitude = self.abs().to_u128()?;
+        if self.negative {
+            0i128.checked_sub_unsigned(magnitude)
+        } else {
+            i128::try_from(magnitude).ok()
+        }
+    }
+}
+
+impl Neg for BigInt {
+    type Output = BigInt;
+
+    fn neg(mut self) -> BigInt {
+        self.negative = !self.negative && !self.is_zero();
+        self
+    }
+}
+
+impl Neg for &BigInt {
+    type Output = BigInt;
+
+    fn neg(self) -> BigInt {
+        -self.clone()
+    }
+}
+
+// Implements the operator for every combination of owned and borrowed operands,
+// along with the assignment form, all forwarding to the borrowed implementation
+macro_rules! forward_binary_op {
+    ($t:ident, $op:ident, $method:ident, $op_assign:ident, $method_assign:ident, $func:ident) => {
+        impl $op<&$t> for &$t {
+            type Output = $t;
+
+            fn $method(self, rhs: &$t) -> $t {
+                $t::$func(self, rhs)
+            }
+        }
+
+        impl $op<$t> for &$t {
+            type Output = $t;
+
+            fn $method(self, rhs: $t) -> $t {
+                $t::$func(self, &rhs)
+            }
+        }
+
+        impl $op<&$t> for $t {
+            type Output = $t;
+
+            fn $method(self, rhs: &$t) -> $t {
+                $t::$func(&self, rhs)
+            }
+        }
+
+        impl $op<$t> for $t {
+            type Output = $t;
+
+            fn $method(self, rhs: $t) -> $t {
+                $t::$func(&self, &rhs)
+            }
+        }
+
+        impl $op_assign<&$t> for $t {
+            fn $method_assign(&mut self, rhs: &$t) {
+                *self = $t::$func(self, rhs);
             }
         }
 
-        Ok(remainder == 0)
+        impl $op_assign<$t> for $t {
+            fn $method_assign(&mut self, rhs: $t) {
+                *self = $t::$func(self, &rhs);
+            }
+        }
+    };
+}
+
+// Shared with the other number types built on BigInt
+pub(crate) use forward_binary_op;
+
+forward_binary_op!(BigInt, Add, add, AddAssign, add_assign, add);
+forward_binary_op!(BigInt, Sub, sub, SubAssign, sub_assign, subtract);
+forward_binary_op!(BigInt, Mul, mul, MulAssign, mul_assign, multiply_by);
+forward_binary_op!(BigInt, Div, div, DivAssign, div_assign, divide_by);
+forward_binary_op!(BigInt, Rem, rem, RemAssign, rem_assign, remainder_by);
+
+// Implements every operator between BigInt and the primitive types, in either order
+// Each primitive is first widened to the given type, then converted with the given function
+macro_rules! primitive_binary_ops {
+    ($($t:ty),*) => {
+        $(
+            primitive_binary_ops!(@op $t, Add, add, AddAssign, add_assign);
+            primitive_binary_ops!(@op $t, Sub, sub, SubAssign, sub_assign);
+            primitive_binary_ops!(@op $t, Mul, mul, MulAssign, mul_assign);
+            primitive_binary_ops!(@op $t, Div, div, DivAssign, div_assign);
+            primitive_binary_ops!(@op $t, Rem, rem, RemAssign, rem_assign);
+        )*
+    };
+    (@op $t:ty, $op:ident, $method:ident, $op_assign:ident, $method_assign:ident) => {
+        impl $op<$t> for &BigInt {
+            type Output = BigInt;
+
+            fn $method(self, rhs: $t) -> BigInt {
+                $op::$method(self, &BigInt::from(rhs))
+            }
+        }
+
+        impl $op<$t> for BigInt {
+            type Output = BigInt;
+
+            fn $method(self, rhs: $t) -> BigInt {
+                $op::$method(&self, &BigInt::from(rhs))
+            }
+        }
+
+        impl $op<&BigInt> for $t {
+            type Output = BigInt;
+
+            fn $method(self, rhs: &BigInt) -> BigInt {
+                $op::$method(&BigInt::from(self), rhs)
+            }
+        }
+
+        impl $op<BigInt> for $t {
+            type Output = BigInt;
+
+            fn $method(self, rhs: BigInt) -> BigInt {
+                $op::$method(&BigInt::from(self), &rhs)
+            }
+        }
+
+        impl $op_assign<$t> for BigInt {
+            fn $method_assign(&mut self, rhs: $t) {
+                *self = $op::$method(&*self, &BigInt::from(rhs));
+            }
+        }
+    };
+}
+
+primitive_binary_ops!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+// Converts from a primitive by widening it to the given type, then passing it to the constructor
+macro_rules! from_primitive {
+    ($target:ident, $convert:ident($wide:ty): $($t:ty),*) => {
+        $(
+            impl From<$t> for $target {
+                fn from(value: $t) -> Self {
+                    Self::$convert(<$wide>::from(value))
+                }
+            }
+        )*
+    };
+}
+
+pub(crate) use from_primitive;
+
+from_primitive!(BigInt, from_u128(u128): u8, u16, u32, u64, u128);
+from_primitive!(BigInt, from_i128(i128): i8, i16, i32, i64, i128);
+
+// Pointer-sized integers are never wider than 128 bits
+impl From<usize> for BigInt {
+    fn from(value: usize) -> Self {
+        Self::from_u128(u128::try_from(value).unwrap())
     }
 }
 
-impl Default for BigInt {
-    fn default() -> Self {
-        Self {
-            value: "0".to_string(),
+impl From<isize> for BigInt {
+    fn from(value: isize) -> Self {
+        Self::from_i128(i128::try_from(value).unwrap())
+    }
+}
+
+// Converts to a primitive, failing with an overflow error if the value is out of its range
+macro_rules! try_from_bigint {
+    ($wide:ty, $convert:ident: $($t:ty),*) => {
+        $(
+            impl TryFrom<&BigInt> for $t {
+                type Error = BigIntError;
+
+                fn try_from(value: &BigInt) -> Result<Self, Self::Error> {
+                    value
+                        .$convert()
+                        .and_then(|wide| <$t>::try_from(wide).ok())
+                        .ok_or(BigIntError::Overflow)
+                }
+            }
+
+            impl TryFrom<BigInt> for $t {
+                type Error = BigIntError;
+
+                fn try_from(value: BigInt) -> Result<Self, Self::Error> {
+                    <$t>::try_from(&value)
+                }
+            }
+        )*
+    };
+}
+
+try_from_bigint!(u128, to_u128: u8, u16, u32, u64, u128, usize);
+try_from_bigint!(i128, to_i128: i8, i16, i32, i64, i128, isize);
+
+impl Sum for BigInt {
+    fn sum<I: Iterator<Item = BigInt>>(iter: I) -> Self {
+        iter.fold(BigInt::default(), |total, value| total + value)
+    }
+}
+
+impl<'a> Sum<&'a BigInt> for BigInt {
+    fn sum<I: Iterator<Item = &'a BigInt>>(iter: I) -> Self {
+        iter.fold(BigInt::default(), |total, value| total + value)
+    }
+}
+
+impl Product for BigInt {
+    fn product<I: Iterator<Item = BigInt>>(iter: I) -> Self {
+        iter.fold(BigInt::from(1u8), |total, value| total * value)
+    }
+}
+
+impl<'a> Product<&'a BigInt> for BigInt {
+    fn product<I: Iterator<Item = &'a BigInt>>(iter: I) -> Self {
+        iter.fold(BigInt::from(1u8), |total, value| total * value)
+    }
+}
+
+impl Integer for BigInt {
+    fn zero() -> Self {
+        Self::default()
+    }
+
+    fn one() -> Self {
+        Self::from(1u8)
+    }
+}
+
+impl Ord for BigInt {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self.negative, other.negative) {
+            (false, true) => Ordering::Greater,
+            (true, false) => Ordering::Less,
+            (false, false) => compare_limbs(&self.limbs, &other.limbs),
+            // The larger magnitude is the smaller value when both are negative
+            (true, true) => compare_limbs(&other.limbs, &self.limbs),
         }
     }
 }
 
+impl PartialOrd for BigInt {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
 impl Display for BigInt {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{}", self.value)
+        f.write_str(&self.to_str_radix(10))
     }
 }
 
 impl FromStr for BigInt {
-    type Err = BigIntParseError;
+    type Err = BigIntError;
+
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.chars().all(|c| c.is_ascii_digit()) {
-            Ok(Self {
-                value: s.to_string(),
+        Self::from_str_radix(s, 10)
+    }
+}
+
+impl BigInt {
+    // Parses the value from digits in the radix (2-36), with an optional leading sign
+    // Letters are used for digits above 9 and may be either case, like the primitive types
+    pub fn from_str_radix(s: &str, radix: u32) -> Result<Self, BigIntError> {
+        assert!(
+            (MIN_RADIX..=MAX_RADIX).contains(&radix),
+            "Radix must be between {MIN_RADIX} and {MAX_RADIX}: {radix}"
+        );
+
+        // An optional sign may come before the digits
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(digits) => (true, digits),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+
+        // Leading zeros are allowed, but there must be at least one digit
+        if digits.is_empty() {
+            return Err(BigIntError::Empty);
+        }
+
+        // Positions are reported relative to the whole string, including any sign
+        let offset = s.len() - digits.len();
+        let values = digits
+            .char_indices()
+            .map(|(index, character)| {
+                character.to_digit(radix).ok_or(BigIntError::InvalidDigit {
+                    character,
+                    position: offset + index,
+                })
             })
-        } else {
-            Err(BigIntParseError::InvalidCharacter)
+            .collect::<Result<Vec<u32>, BigIntError>>()?;
+
+        // Consume a limb's worth of digits at a time, so each chunk is a single multiply and add
+        let (chunk_base, chunk_digits) = radix_chunk(radix);
+        let mut limbs = Vec::with_capacity(values.len() / chunk_digits + 1);
+        let (head, tail) = values.split_at(values.len() % chunk_digits);
+
+        if !head.is_empty() {
+            multiply_add_small(&mut limbs, 1, parse_chunk(head, radix));
+        }
+        for chunk in tail.chunks(chunk_digits) {
+            multiply_add_small(&mut limbs, chunk_base, parse_chunk(chunk, radix));
+        }
+
+        Ok(Self::from_parts(limbs, negative))
+    }
+
+    // Formats the value using digits in the radix (2-36), with lowercase letters above 9
+    #[must_use]
+    pub fn to_str_radix(&self, radix: u32) -> String {
+        assert!(
+            (MIN_RADIX..=MAX_RADIX).contains(&radix),
+            "Radix must be between {MIN_RADIX} and {MAX_RADIX}: {radix}"
+        );
+
+        if self.is_zero() {
+            return "0".to_string();
+        }
+
+        // Repeatedly divide by the chunk base, collecting the digits from least significant first
+        let (chunk_base, chunk_digits) = radix_chunk(radix);
+        let mut digits = Vec::with_capacity(self.limbs.len() * 32);
+        let mut limbs = self.limbs.clone();
+        while !limbs.is_empty() {
+            let (quotient, mut chunk) = divide_limbs_small(&limbs, chunk_base);
+            limbs = quotient;
+            trim_limbs(&mut limbs);
+
+            // Every chunk except the most significant is padded with zeros to the full width
+            for _ in 0..chunk_digits {
+                if limbs.is_empty() && chunk == 0 {
+                    break;
+                }
+                digits.push(char::from_digit(chunk % radix, radix).unwrap());
+                chunk /= radix;
+            }
+        }
+
+        if self.negative {
+            digits.push('-');
         }
+        digits.iter().rev().collect()
     }
 }
 
-fn add_string_numbers(a: &str, b: &str) -> String {
-    let mut a: Vec<char> = a.chars().collect();
-    let mut b: Vec<char> = b.chars().collect();
+// A numeral system where the digits are centered on zero, so negative values need no sign
+// For example, the alphabet "=-012" is balanced base 5, where the digits are worth -2 to 2
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct BalancedRadix {
+    digits: Vec<char>,
+}
 
-    let mut carry = 0;
-    let mut result = Vec::with_capacity(a.len() + b.len());
+impl BalancedRadix {
+    // Creates the system from its digits, ordered from lowest to highest value
+    #[must_use]
+    pub fn new(alphabet: &str) -> Self {
+        let digits: Vec<char> = alphabet.chars().collect();
+        assert!(
+            digits.len() >= 3 && digits.len() % 2 == 1,
+            "Balanced alphabet must have an odd number of digits (at least 3): {alphabet:?}"
+        );
+        assert!(
+            digits
+                .iter()
+                .enumerate()
+                .all(|(index, digit)| !digits[..index].contains(digit)),
+            "Balanced alphabet must not repeat digits: {alphabet:?}"
+        );
+
+        Self { digits }
+    }
+
+    #[must_use]
+    pub fn radix(&self) -> u32 {
+        u32::try_from(self.digits.len()).unwrap()
+    }
+
+    // Parses the value from the digits, from most to least significant
+    pub fn parse(&self, s: &str) -> Result<BigInt, BigIntError> {
+        if s.is_empty() {
+            return Err(BigIntError::Empty);
+        }
+
+        let radix = self.radix();
+        let zero_index = i64::from(radix / 2);
+        s.char_indices()
+            .try_fold(BigInt::default(), |value, (position, character)| {
+                let index = self
+                    .digits
+                    .iter()
+                    .position(|&digit| digit == character)
+                    .ok_or(BigIntError::InvalidDigit {
+                        character,
+                        position,
+                    })?;
+                Ok(value * radix + (i64::try_from(index).unwrap() - zero_index))
+            })
+    }
+
+    // Formats the value using the digits, from most to least significant
+    #[must_use]
+    pub fn format(&self, value: &BigInt) -> String {
+        let radix = self.radix();
+        let zero_index = radix / 2;
+        let mut digits = Vec::new();
+
+        // Work with the magnitude, digits above the zero digit borrow from the next place
+        let mut limbs = value.limbs.clone();
+        while !limbs.is_empty() {
+            let (mut quotient, remainder) = divide_limbs_small(&limbs, radix);
+            let index = if remainder > zero_index {
+                multiply_add_small(&mut quotient, 1, 1);
+                remainder + zero_index - radix
+            } else {
+                remainder + zero_index
+            };
+
+            // Negating a balanced value mirrors each digit around zero
+            let index = if value.negative {
+                radix - 1 - index
+            } else {
+                index
+            };
+            digits.push(self.digits[index as usize]);
+
+            limbs = quotient;
+            trim_limbs(&mut limbs);
+        }
 
-    while !a.is_empty() || !b.is_empty() || carry > 0 {
-        let mut sum = carry;
+        if digits.is_empty() {
+            digits.push(self.digits[zero_index as usize]);
+        }
+        digits.iter().rev().collect()
+    }
+}
+
+// Gets the largest power of the radix that fits within a limb, and how many digits it holds
+fn radix_chunk(radix: u32) -> (u32, usize) {
+    let (mut base, mut digits) = (radix, 1);
+    while let Some(next) = base.checked_mul(radix) {
+        base = next;
+        digits += 1;
+    }
+    (base, digits)
+}
 
-        if let Some(&digit) = a.last() {
-            sum += digit.to_digit(10).unwrap();
-            a.pop();
+// Combines digits that have already been validated into a single limb
+fn parse_chunk(digits: &[u32], radix: u32) -> u32 {
+    digits.iter().fold(0, |value, &digit| value * radix + digit)
+}
+
+// Multiplies every integer from low to high (inclusive), splitting the range in half so that
+// the operands stay balanced and can take advantage of Karatsuba multiplication
+fn product_range(low: u32, high: u32) -> BigInt {
+    match high.checked_sub(low) {
+        None => BigInt::from(1u8),
+        Some(0) => BigInt::from(low),
+        Some(1) => BigInt::from(u64::from(low) * u64::from(high)),
+        Some(span) => {
+            let middle = low + span / 2;
+            product_range(low, middle) * product_range(middle + 1, high)
         }
+    }
+}
+
+// Removes the most significant zero limbs
+fn trim_limbs(limbs: &mut Vec<u32>) {
+    while let Some(&0) = limbs.last() {
+        limbs.pop();
+    }
+}
+
+// Splits a double-width value into its (low, high) limbs
+#[allow(clippy::cast_possible_truncation)]
+fn split_u64(value: u64) -> (u32, u32) {
+    (value as u32, (value >> LIMB_BITS) as u32)
+}
+
+// Multiplies the limbs in-place by a single limb, then adds another single limb
+fn multiply_add_small(limbs: &mut Vec<u32>, multiplier: u32, addend: u32) {
+    let mut carry = u64::from(addend);
+
+    for limb in limbs.iter_mut() {
+        let (low, high) = split_u64(u64::from(*limb) * u64::from(multiplier) + carry);
+        *limb = low;
+        carry = u64::from(high);
+    }
+
+    if carry > 0 {
+        limbs.push(split_u64(carry).0);
+    }
+}
+
+// Adds two signed magnitudes, subtracting the smaller magnitude when the signs differ
+fn add_signed(a: &[u32], a_negative: bool, b: &[u32], b_negative: bool) -> BigInt {
+    if a_negative == b_negative {
+        return BigInt::from_parts(add_limbs(a, b), a_negative);
+    }
+
+    match compare_limbs(a, b) {
+        Ordering::Less => BigInt::from_parts(subtract_limbs(b, a), b_negative),
+        _ => BigInt::from_parts(subtract_limbs(a, b), a_negative),
+    }
+}
+
+// Compares two trimmed limb vectors by numeric value
+fn compare_limbs(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+fn add_limbs(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (longer, shorter) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    let mut result = Vec::with_capacity(longer.len() + 1);
+    let mut carry = 0;
+
+    for (index, &limb) in longer.iter().enumerate() {
+        let other = shorter.get(index).copied().unwrap_or_default();
+        let (sum, high) = split_u64(u64::from(limb) + u64::from(other) + carry);
+        result.push(sum);
+        carry = u64::from(high);
+    }
+
+    if carry > 0 {
+        result.push(split_u64(carry).0);
+    }
+
+    result
+}
+
+// Subtracts B from A, where A must be greater than or equal to B
+fn subtract_limbs(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let mut result = Vec::with_capacity(a.len());
+    let mut borrow = false;
+
+    for (index, &limb) in a.iter().enumerate() {
+        let other = b.get(index).copied().unwrap_or_default();
+        let (difference, borrow_a) = limb.overflowing_sub(other);
+        let (difference, borrow_b) = difference.overflowing_sub(u32::from(borrow));
+        result.push(difference);
+        borrow = borrow_a || borrow_b;
+    }
+
+    result
+}
+
+// Multiplies the limbs, using Karatsuba's algorithm when both operands are large
+// The result may contain trailing zero limbs
+fn multiply_limbs(a: &[u32], b: &[u32]) -> Vec<u32> {
+    let (longer, shorter) = if a.len() >= b.len() { (a, b) } else { (b, a) };
+    if shorter.len() < KARATSUBA_THRESHOLD {
+        return schoolbook_multiply(longer, shorter);
+    }
+
+    let mut result = vec![0; a.len() + b.len()];
+    let half = longer.len() / 2;
 
-        if let Some(&digit) = b.last() {
-            sum += digit.to_digit(10).unwrap();
-            b.pop();
+    // Karatsuba needs operands of similar size, so split the longer one into shorter-sized pieces
+    if shorter.len() <= half {
+        for (index, chunk) in longer.chunks(shorter.len()).enumerate() {
+            add_limbs_at(
+                &mut result,
+                &multiply_limbs(chunk, shorter),
+                index * shorter.len(),
+            );
         }
+        return result;
+    }
+
+    // With each operand split as (high * B + low), the product is
+    // high*high * B^2 + ((low + high)(low + high) - low*low - high*high) * B + low*low
+    // which takes three half-sized multiplications instead of four
+    let (a_low, a_high) = longer.split_at(half);
+    let (b_low, b_high) = shorter.split_at(half);
+    let low = multiply_limbs(a_low, b_low);
+    let high = multiply_limbs(a_high, b_high);
+    let mut middle = multiply_limbs(&add_limbs(a_low, a_high), &add_limbs(b_low, b_high));
+    subtract_limbs_in_place(&mut middle, &low);
+    subtract_limbs_in_place(&mut middle, &high);
 
-        carry = sum / 10;
-        result.push(char::from_digit(sum % 10, 10).unwrap());
+    add_limbs_at(&mut result, &low, 0);
+    add_limbs_at(&mut result, &middle, half);
+    add_limbs_at(&mut result, &high, half * 2);
+    result
+}
+
+// Squares the limbs, which needs only three half-sized squarings with Karatsuba
+// The result may contain trailing zero limbs
+fn square_limbs(limbs: &[u32]) -> Vec<u32> {
+    if limbs.len() < KARATSUBA_THRESHOLD {
+        return schoolbook_square(limbs);
     }
 
-    result.iter().rev().collect()
+    let half = limbs.len() / 2;
+    let (low_limbs, high_limbs) = limbs.split_at(half);
+    let low = square_limbs(low_limbs);
+    let high = square_limbs(high_limbs);
+    let mut middle = square_limbs(&add_limbs(low_limbs, high_limbs));
+    subtract_limbs_in_place(&mut middle, &low);
+    subtract_limbs_in_place(&mut middle, &high);
+
+    let mut result = vec![0; limbs.len() * 2];
+    add_limbs_at(&mut result, &low, 0);
+    add_limbs_at(&mut result, &middle, half);
+    add_limbs_at(&mut result, &high, half * 2);
+    result
 }
 
-fn multiply_string_numbers(a: &str, b: &str) -> String {
-    let a: Vec<char> = a.chars().collect();
-    let b: Vec<char> = b.chars().collect();
+fn schoolbook_multiply(a: &[u32], b: &[u32]) -> Vec<u32> {
+    if a.is_empty() || b.is_empty() {
+        return Vec::new();
+    }
 
     let mut result = vec![0; a.len() + b.len()];
 
-    for (a_idx, &a_char) in a.iter().rev().enumerate() {
-        for (b_idx, &b_char) in b.iter().rev().enumerate() {
-            let prod = a_char.to_digit(10).unwrap() * b_char.to_digit(10).unwrap();
-            let sum = prod + result[a_idx + b_idx];
+    for (a_idx, &a_limb) in a.iter().enumerate() {
+        let mut carry = 0;
 
-            result[a_idx + b_idx] = sum % 10;
-            result[a_idx + b_idx + 1] += sum / 10;
+        for (b_idx, &b_limb) in b.iter().enumerate() {
+            let product =
+                u64::from(a_limb) * u64::from(b_limb) + u64::from(result[a_idx + b_idx]) + carry;
+            let (low, high) = split_u64(product);
+            result[a_idx + b_idx] = low;
+            carry = u64::from(high);
         }
+
+        result[a_idx + b.len()] = split_u64(carry).0;
     }
 
-    while let Some(&0) = result.last() {
-        result.pop();
+    result
+}
+
+// Squares the limbs, calculating each cross product once and doubling it,
+// which takes roughly half the multiplications of the general schoolbook method
+fn schoolbook_square(limbs: &[u32]) -> Vec<u32> {
+    let mut result = vec![0; limbs.len() * 2];
+
+    // Sum every product of two different limbs
+    for (i, &i_limb) in limbs.iter().enumerate() {
+        let mut carry = 0;
+
+        for (j, &j_limb) in limbs.iter().enumerate().skip(i + 1) {
+            let product = u64::from(i_limb) * u64::from(j_limb) + u64::from(result[i + j]) + carry;
+            let (low, high) = split_u64(product);
+            result[i + j] = low;
+            carry = u64::from(high);
+        }
+
+        result[i + limbs.len()] = split_u64(carry).0;
+    }
+
+    // Each cross product appears twice in the square, then add the square of each limb
+    let mut result = shift_limbs_left(&result, 1);
+    result.resize(limbs.len() * 2, 0);
+
+    let mut carry = 0;
+    for (i, &limb) in limbs.iter().enumerate() {
+        let (square_low, square_high) = split_u64(u64::from(limb) * u64::from(limb));
+        for (index, part) in [(i * 2, square_low), (i * 2 + 1, square_high)] {
+            let (sum, high) = split_u64(u64::from(result[index]) + u64::from(part) + carry);
+            result[index] = sum;
+            carry = u64::from(high);
+        }
     }
 
     result
-        .into_iter()
-        .rev()
-        .map(|d| char::from_digit(d, 10).unwrap())
-        .collect()
 }
 
-fn divide_string_numbers(dividend: &str, divisor: &str) -> Result<String, String> {
-    if divisor == "0" {
-        return Err("Division by zero".into());
+// Adds the value into the limbs, starting at the offset
+// The limbs must be large enough to hold the sum
+fn add_limbs_at(limbs: &mut [u32], value: &[u32], offset: usize) {
+    let mut carry = 0;
+    let mut index = offset;
+
+    for &limb in value {
+        let sum = u64::from(limb) + carry;
+        if index >= limbs.len() {
+            debug_assert_eq!(sum, 0, "Sum does not fit within the limbs");
+            break;
+        }
+        let (low, high) = split_u64(u64::from(limbs[index]) + sum);
+        limbs[index] = low;
+        carry = u64::from(high);
+        index += 1;
+    }
+
+    while carry > 0 {
+        let (low, high) = split_u64(u64::from(limbs[index]) + carry);
+        limbs[index] = low;
+        carry = u64::from(high);
+        index += 1;
+    }
+}
+
+// Subtracts the value from the limbs in-place, which must not be smaller than the value
+fn subtract_limbs_in_place(limbs: &mut [u32], value: &[u32]) {
+    let mut borrow = false;
+
+    for (index, limb) in limbs.iter_mut().enumerate() {
+        let other = value.get(index).copied().unwrap_or_default();
+        if other == 0 && !borrow && index >= value.len() {
+            break;
+        }
+        let (difference, borrow_a) = limb.overflowing_sub(other);
+        let (difference, borrow_b) = difference.overflowing_sub(u32::from(borrow));
+        *limb = difference;
+        borrow = borrow_a || borrow_b;
+    }
+}
+
+// Divides the limbs by any non-zero divisor, returning the quotient and remainder
+// Implements Knuth's Algorithm D (The Art of Computer Programming, Vol. 2, 4.3.1)
+#[allow(
+    clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_sign_loss
+)]
+fn divide_limbs(dividend: &[u32], divisor: &[u32]) -> (Vec<u32>, Vec<u32>) {
+    if compare_limbs(dividend, divisor) == Ordering::Less {
+        return (Vec::new(), dividend.to_vec());
+    }
+
+    if let [divisor] = divisor[..] {
+        let (quotient, remainder) = divide_limbs_small(dividend, divisor);
+        return (quotient, vec![remainder]);
+    }
+
+    // Normalize so the top bit of the divisor is set, which keeps each quotient estimate
+    // within two of the true digit; the dividend gains an extra limb for the overflow
+    let shift = divisor.last().unwrap().leading_zeros();
+    let divisor = shift_limbs_left(divisor, shift);
+    let mut remainder = shift_limbs_left(dividend, shift);
+    remainder.resize(dividend.len() + 1, 0);
+
+    let n = divisor.len();
+    let m = dividend.len() - n;
+    let mut quotient = vec![0; m + 1];
+
+    let top = u64::from(divisor[n - 1]);
+    let second = u64::from(divisor[n - 2]);
+
+    for j in (0..=m).rev() {
+        // Estimate the quotient digit from the top two limbs, then correct it using the third
+        let numerator =
+            (u64::from(remainder[j + n]) << LIMB_BITS) | u64::from(remainder[j + n - 1]);
+        let mut estimate = numerator / top;
+        let mut estimate_rem = numerator % top;
+
+        while estimate > u64::from(u32::MAX)
+            || estimate * second > ((estimate_rem << LIMB_BITS) | u64::from(remainder[j + n - 2]))
+        {
+            estimate -= 1;
+            estimate_rem += top;
+            if estimate_rem > u64::from(u32::MAX) {
+                break;
+            }
+        }
+
+        // Multiply and subtract the estimate times the divisor from the current window
+        let mut borrow: i64 = 0;
+        for i in 0..n {
+            let product = estimate * u64::from(divisor[i]);
+            let difference =
+                i64::from(remainder[i + j]) - borrow - (product & u64::from(u32::MAX)) as i64;
+            remainder[i + j] = difference as u32;
+            borrow = (product >> LIMB_BITS) as i64 - (difference >> LIMB_BITS);
+        }
+        let difference = i64::from(remainder[j + n]) - borrow;
+        remainder[j + n] = difference as u32;
+
+        // The estimate was one too large, so add the divisor back in
+        if difference < 0 {
+            estimate -= 1;
+            let mut carry = 0;
+            for i in 0..n {
+                let sum = u64::from(remainder[i + j]) + u64::from(divisor[i]) + carry;
+                remainder[i + j] = sum as u32;
+                carry = sum >> LIMB_BITS;
+            }
+            remainder[j + n] = remainder[j + n].wrapping_add(carry as u32);
+        }
+
+        quotient[j] = estimate as u32;
     }
 
-    let mut result: Vec<char> = Vec::new();
-    let mut remainder: i128 = 0;
-    let divisor = divisor.parse::<i128>().unwrap();
+    // Undo the normalization to get the true remainder
+    remainder.truncate(n);
+    let mut remainder = shift_limbs_right(&remainder, shift);
+    trim_limbs(&mut quotient);
+    trim_limbs(&mut remainder);
+    (quotient, remainder)
+}
+
+// Shifts the limbs left by less than a full limb, growing by one limb if needed
+fn shift_limbs_left(limbs: &[u32], shift: u32) -> Vec<u32> {
+    if shift == 0 {
+        return limbs.to_vec();
+    }
 
-    for digit_char in dividend.chars() {
-        let digit = i128::from(digit_char.to_digit(10).unwrap());
-        remainder = remainder * 10 + digit;
+    let mut result = Vec::with_capacity(limbs.len() + 1);
+    let mut carry = 0;
+    for &limb in limbs {
+        result.push((limb << shift) | carry);
+        carry = limb >> (LIMB_BITS - shift);
+    }
+    if carry > 0 {
+        result.push(carry);
+    }
+    result
+}
 
-        let quotient_digit = remainder / divisor;
-        remainder %= divisor;
+// Shifts the limbs right by less than a full limb
+fn shift_limbs_right(limbs: &[u32], shift: u32) -> Vec<u32> {
+    if shift == 0 {
+        return limbs.to_vec();
+    }
 
-        result.push(char::from_digit(u32::try_from(quotient_digit).unwrap(), 10).unwrap());
+    let mut result = vec![0; limbs.len()];
+    let mut carry = 0;
+    for (index, &limb) in limbs.iter().enumerate().rev() {
+        result[index] = (limb >> shift) | carry;
+        carry = limb << (LIMB_BITS - shift);
     }
+    result
+}
+
+// Divides the limbs by a single limb, returning the quotient and remainder
+fn divide_limbs_small(limbs: &[u32], divisor: u32) -> (Vec<u32>, u32) {
+    let divisor = u64::from(divisor);
+    let mut quotient = vec![0; limbs.len()];
+    let mut remainder = 0;
 
-    // Remove leading zeros
-    while result.len() > 1 && result[0] == '0' {
-        result.remove(0);
+    // Work from the most significant limb downwards, like long division by hand
+    for (index, &limb) in limbs.iter().enumerate().rev() {
+        let current = (remainder << LIMB_BITS) | u64::from(limb);
+        quotient[index] = split_u64(current / divisor).0;
+        remainder = current % divisor;
     }
 
-    Ok(result.iter().collect())
+    trim_limbs(&mut quotient);
+    (quotient, split_u64(remainder).0)
 }
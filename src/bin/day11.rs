@@ -1,4 +1,5 @@
 #![warn(clippy::pedantic)]
+use advent_of_rust_2022::lcm;
 use std::cell::{Cell, RefCell};
 use std::collections::VecDeque;
 use std::error::Error;
@@ -54,14 +55,12 @@ fn main() -> Result<(), Box<dyn Error>> {
     // Determine the amount of monkey business after 10000 rounds, without worry reduction (part 2)
     let monkeys: Vec<Monkey> = read_monkey_data(&mut reader);
 
-    // Part of Chinese-Remainder Theorem
-    // https://en.wikipedia.org/wiki/Chinese_remainder_theorem
-    // M = product of all modulo
+    // Every divisibility test gives the same answer modulo the lcm of all the divisors
     // This can now be used to 'limit' our worry levels while being divisible by all monkeys
     let modulo: i64 = monkeys
         .iter()
         .map(|m| i64::from(m.test_divisible_by))
-        .product();
+        .fold(1, lcm);
 
     let monkey_business = calc_monkey_business(&monkeys, 10_000, |worry| worry % modulo);
     println!("[Part II] The level of monkey business after 10000 rounds is {monkey_business}");
@@ -20,11 +20,11 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     // Get the start and goal locations (S -> E)
     let start = match grid.find(|val| val == &Terrain::StartLocation) {
-        Some((x, y)) => Point::new(i32::try_from(x).unwrap(), i32::try_from(y).unwrap()),
+        Some((x, y)) => Point::from_grid(x, y).unwrap(),
         None => panic!("Unable to find start location!"),
     };
     let goal = match grid.find(|val| val == &Terrain::Goal) {
-        Some((x, y)) => Point::new(i32::try_from(x).unwrap(), i32::try_from(y).unwrap()),
+        Some((x, y)) => Point::from_grid(x, y).unwrap(),
         None => panic!("Unable to find goal location!"),
     };
 
@@ -43,7 +43,7 @@ fn main() -> Result<(), Box<dyn Error>> {
     let possible_starts: Vec<Point> = grid
         .find_all(|val| matches!(val, Terrain::StartLocation | Terrain::Height(1)))
         .iter()
-        .map(|(x, y)| Point::new(i32::try_from(*x).unwrap(), i32::try_from(*y).unwrap()))
+        .map(|&(x, y)| Point::from_grid(x, y).unwrap())
         .collect();
 
     // Determine the best (shortest) path from any start location (part 2)
@@ -70,32 +70,30 @@ fn main() -> Result<(), Box<dyn Error>> {
 }
 
 // Calculate the movement cost from one space to another (or None if impossible move)
-#[allow(clippy::cast_sign_loss)]
 fn calc_move_cost(grid: &RowGrid<Terrain>, from: Point, to: Point) -> Option<u32> {
     // Out of bounds
-    if to.x < 0 || to.y < 0 {
-        return None;
-    }
+    let (to_x, to_y) = to.to_grid()?;
+    let (from_x, from_y) = from.to_grid()?;
 
     // Determine the neighbor's height, or None if not valid
-    let neighbor_height = match grid.cell(to.x as usize, to.y as usize) {
+    let neighbor_height = match grid.cell(to_x, to_y) {
         // The goal is always considered the 'z' height (letter 26)
         Some(Terrain::Goal) => 26,
-        Some(Terrain::Height(height)) => i32::from(*height),
+        Some(Terrain::Height(height)) => u32::from(*height),
         _ => return None,
     };
 
     // Determine the current space's height
-    let this_height = match grid.cell(from.x as usize, from.y as usize) {
+    let this_height = match grid.cell(from_x, from_y) {
         // The start is always considered the 'a' height (letter 1)
         Some(Terrain::StartLocation) => 1,
-        Some(Terrain::Height(height)) => i32::from(*height),
+        Some(Terrain::Height(height)) => u32::from(*height),
         _ => return None,
     };
 
     // Only allow a +1 height increase per move
     if neighbor_height <= this_height + 1 {
-        Some(neighbor_height as u32)
+        Some(neighbor_height)
     } else {
         None
     }
@@ -103,7 +101,6 @@ fn calc_move_cost(grid: &RowGrid<Terrain>, from: Point, to: Point) -> Option<u32
 
 // Visualizes the path taken on the grid, useful for debugging
 #[allow(dead_code)]
-#[allow(clippy::cast_sign_loss)]
 fn visualize_path(path: &[Point], width: usize, height: usize) {
     let mut cells = vec!['_'; width * height];
 
@@ -120,9 +117,8 @@ fn visualize_path(path: &[Point], width: usize, height: usize) {
             _ => '?',
         };
 
-        if let Some(point) = current {
-            let index = (point.y as usize * width) + point.x as usize;
-            cells[index] = char;
+        if let Some((x, y)) = current.and_then(Point::to_grid) {
+            cells[y * width + x] = char;
         }
     }
 
@@ -101,7 +101,7 @@ fn main() -> Result<(), Box<dyn Error>> {
         .expect("Unable to find divider packet [[6]] in list");
 
     let decoder_key = (divider_index_2 + 1) * (divider_index_6 + 1);
-    println!("[Part II] The decoder key is {decoder_key}",);
+    println!("[Part II] The decoder key is {decoder_key}");
     Ok(())
 }
 
@@ -1,7 +1,6 @@
 #![warn(clippy::pedantic)]
 
-use advent_of_rust_2022::{Point, UniformGrid};
-use std::collections::VecDeque;
+use advent_of_rust_2022::{BoundingBox, Point, PointParseError, Rect, UniformGrid};
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 use std::fs::File;
@@ -42,16 +41,14 @@ impl Display for ScanTrace {
     }
 }
 
-const SAND_SPAWN_X: i32 = 500;
-const SAND_SPAWN_Y: i32 = 0;
+const SAND_SPAWN: Point = Point { x: 500, y: 0 };
 
-#[allow(clippy::cast_sign_loss)]
 fn main() -> Result<(), Box<dyn Error>> {
     let file = File::open("data/day14_input.txt")?;
     let mut reader = BufReader::new(file);
 
     // Load all the scan traces from input file
-    let scan_traces = read_scan_traces(&mut reader);
+    let scan_traces = read_scan_traces(&mut reader)?;
 
     // Add all of the traces as rock paths to the 2D terrain grid
     let mut terrain_grid: UniformGrid<Terrain> = UniformGrid::new(800, 200);
@@ -65,18 +62,17 @@ fn main() -> Result<(), Box<dyn Error>> {
 
     // Attempt to spawn sand until it cannot settle any longer (part 1)
     let mut sand_count_p1: usize = 0;
-    while let Some(point) = try_place_sand(&terrain_grid, Point::new(SAND_SPAWN_X, SAND_SPAWN_Y)) {
-        terrain_grid.set_cell(point.x as usize, point.y as usize, Terrain::Sand);
+    while let Some((x, y)) = try_place_sand(&terrain_grid, SAND_SPAWN).and_then(|pt| pt.to_grid()) {
+        terrain_grid.set_cell(x, y, Terrain::Sand);
         sand_count_p1 += 1;
     }
 
     // Attempt to spawn sand until it cannot settle any longer (part 2)
     let mut sand_count_p2: usize = 0;
-    while let Some(point) = try_place_sand(
-        &terrain_grid_with_floor,
-        Point::new(SAND_SPAWN_X, SAND_SPAWN_Y),
-    ) {
-        terrain_grid_with_floor.set_cell(point.x as usize, point.y as usize, Terrain::Sand);
+    while let Some((x, y)) =
+        try_place_sand(&terrain_grid_with_floor, SAND_SPAWN).and_then(|pt| pt.to_grid())
+    {
+        terrain_grid_with_floor.set_cell(x, y, Terrain::Sand);
         sand_count_p2 += 1;
     }
 
@@ -97,7 +93,7 @@ fn main() -> Result<(), Box<dyn Error>> {
 }
 
 // Attempts to read the scan trace data
-fn read_scan_traces(reader: &mut impl BufRead) -> Vec<ScanTrace> {
+fn read_scan_traces(reader: &mut impl BufRead) -> Result<Vec<ScanTrace>, PointParseError> {
     let mut scans: Vec<ScanTrace> = Vec::with_capacity(100);
 
     // Read each line as a scan trace, skipping empty lines
@@ -111,36 +107,21 @@ fn read_scan_traces(reader: &mut impl BufRead) -> Vec<ScanTrace> {
         };
 
         // Split the line by the `->` string and attempt to parse as point collection
-        let points: Vec<Point> = line
+        let points = line
             .split("->")
-            .map(|pt| {
-                let (x, y) = pt
-                    .split_once(',')
-                    .unwrap_or_else(|| panic!("Invalid point: {pt}"));
-                let x = x
-                    .trim()
-                    .parse()
-                    .unwrap_or_else(|_| panic!("Invalid X coordinate: {x}"));
-                let y = y
-                    .trim()
-                    .parse()
-                    .unwrap_or_else(|_| panic!("Invalid Y coordinate: {y}"));
-
-                Point::new(x, y)
-            })
-            .collect();
+            .map(str::parse)
+            .collect::<Result<Vec<Point>, _>>()?;
 
         scans.push(ScanTrace { points });
     }
 
-    scans
+    Ok(scans)
 }
 
 fn visualize_grid(grid: &UniformGrid<Terrain>) {
-    let rocks = grid.find_all(|x| *x == Terrain::Rock);
-    let min_x = rocks.iter().map(|&(x, _)| x).min().unwrap_or_default();
-    let max_x = rocks.iter().map(|&(x, _)| x).max().unwrap_or_default();
-    let max_y = rocks.iter().map(|&(_, y)| y).max().unwrap_or_default();
+    let bounds = rock_bounds(grid).unwrap_or_default();
+    let (min_x, _) = bounds.min.to_grid().unwrap_or_default();
+    let (max_x, max_y) = bounds.max.to_grid().unwrap_or_default();
 
     print!("     ");
     for x in min_x..=max_x {
@@ -163,7 +144,7 @@ fn visualize_grid(grid: &UniformGrid<Terrain>) {
     for y in 0..=max_y {
         print!(" {y: >3} ");
         for x in min_x..=max_x {
-            if x == SAND_SPAWN_X as usize && y == SAND_SPAWN_Y as usize {
+            if Point::from_grid(x, y) == Some(SAND_SPAWN) {
                 print!("+");
             } else {
                 let terrain = grid.cell(x, y).unwrap_or(&Terrain::Air);
@@ -175,58 +156,24 @@ fn visualize_grid(grid: &UniformGrid<Terrain>) {
 }
 
 // Adds a path of rock to the grid
-#[allow(clippy::cast_sign_loss)]
 fn add_rock_path(grid: &mut UniformGrid<Terrain>, path: &ScanTrace) {
-    if path.points.is_empty() {
-        return;
-    }
-
-    let mut points = VecDeque::from(path.points.clone());
-    let start_point = points.pop_front().unwrap();
-
-    let mut x = start_point.x as usize;
-    let mut y = start_point.y as usize;
-
-    while let Some(next_point) = points.pop_front() {
-        grid.set_cell(x, y, Terrain::Rock);
-
-        while x != next_point.x as usize {
-            grid.set_cell(x, y, Terrain::Rock);
-            if x < next_point.x as usize {
-                x += 1;
-            } else {
-                x -= 1;
-            }
-        }
-
-        while y != next_point.y as usize {
-            grid.set_cell(x, y, Terrain::Rock);
-            if y < next_point.y as usize {
-                y += 1;
-            } else {
-                y -= 1;
-            }
-        }
-    }
+    grid.draw_polyline(&path.points, &Terrain::Rock);
+}
 
-    grid.set_cell(x, y, Terrain::Rock);
+// Gets the bounding box around all rock in the grid (if any)
+fn rock_bounds(grid: &UniformGrid<Terrain>) -> Option<Rect> {
+    grid.find_all(|terrain| *terrain == Terrain::Rock)
+        .into_iter()
+        .filter_map(|(x, y)| Point::from_grid(x, y))
+        .bounding_box()
 }
 
 // Add the rock floor, where the floor is 2 greater than the highest Y point
 fn add_rock_floor(grid: &mut UniformGrid<Terrain>) {
-    let floor_y = grid
-        .find_all(|terrain| *terrain == Terrain::Rock)
-        .iter()
-        .map(|&(_, y)| y)
-        .max()
-        .unwrap_or_default()
-        + 2;
-
-    let floor_left = Point::new(0, i32::try_from(floor_y).unwrap());
-    let floor_right = Point::new(
-        i32::try_from(grid.width()).unwrap(),
-        i32::try_from(floor_y).unwrap(),
-    );
+    let floor_y = rock_bounds(grid).map_or(0, |bounds| bounds.max.y) + 2;
+
+    let floor_left = Point::new(0, floor_y);
+    let floor_right = Point::new(i32::try_from(grid.width()).unwrap(), floor_y);
 
     add_rock_path(
         grid,
@@ -237,10 +184,8 @@ fn add_rock_floor(grid: &mut UniformGrid<Terrain>) {
 }
 
 // Tries to place a piece of stand in the grid, returning the settled position (if possible)
-#[allow(clippy::cast_sign_loss)]
 fn try_place_sand(grid: &UniformGrid<Terrain>, initial: Point) -> Option<Point> {
-    let mut x = initial.x as usize;
-    let mut y = initial.y as usize;
+    let (mut x, mut y) = initial.to_grid()?;
 
     // If the initial is sand, it is entirely blocked
     if grid.cell(x, y).unwrap_or(&Terrain::Air) == &Terrain::Sand {
@@ -284,9 +229,6 @@ fn try_place_sand(grid: &UniformGrid<Terrain>, initial: Point) -> Option<Point>
         }
 
         // We have settled here, unable to fall further
-        return Some(Point::new(
-            i32::try_from(x).unwrap(),
-            i32::try_from(y).unwrap(),
-        ));
+        return Point::from_grid(x, y);
     }
 }
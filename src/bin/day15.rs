@@ -1,19 +1,35 @@
 #![warn(clippy::pedantic)]
 
-use advent_of_rust_2022::{manhattan_distance, Point};
+use advent_of_rust_2022::{
+    manhattan_distance, Diamond, Interval, IntervalSet, Point, PointParseError,
+};
+use std::collections::HashSet;
 use std::error::Error;
 use std::fmt::{Display, Formatter};
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 
-#[derive(Debug, Eq, PartialEq)]
-enum Coverage {
-    Uncovered,
-    Covered,
-    Sensor,
-    Beacon,
+// The puzzle and example inputs use different rows and search areas
+struct PuzzleConfig {
+    input_path: &'static str,
+    row: i32,
+    search_bound: i32,
 }
 
+const PUZZLE: PuzzleConfig = PuzzleConfig {
+    input_path: "data/day15_input.txt",
+    row: 2_000_000,
+    search_bound: 4_000_000,
+};
+
+const EXAMPLE: PuzzleConfig = PuzzleConfig {
+    input_path: "data/day15_input_example.txt",
+    row: 10,
+    search_bound: 20,
+};
+
+const TUNING_MULTIPLIER: i64 = 4_000_000;
+
 #[derive(Debug, Clone)]
 struct Sensor {
     pub location: Point,
@@ -31,6 +47,12 @@ impl Sensor {
             self.beacon.y,
         ) as i32
     }
+
+    // Gets the area covered by the sensor, everything up to (and including) the closest beacon
+    #[must_use]
+    pub fn coverage(&self) -> Diamond {
+        Diamond::from_edge(self.location, self.beacon)
+    }
 }
 
 impl Display for Sensor {
@@ -48,87 +70,64 @@ impl Display for Sensor {
 }
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let file = File::open("data/day15_input.txt")?;
+    // Pass `--example` to run against the example input instead
+    let config = if std::env::args().any(|arg| arg == "--example") {
+        EXAMPLE
+    } else {
+        PUZZLE
+    };
+
+    let file = File::open(config.input_path)?;
     let mut reader = BufReader::new(file);
 
     // Reads the sensor data from the input file
-    let sensors = read_sensors(&mut reader);
+    let sensors = read_sensors(&mut reader)?;
 
-    // Determine the cover for the row (part 1)
-    let row: i32 = 2_000_000;
-    let mut coverage: usize = 0;
-    iter_row_coverage(&sensors, row, |_, c| {
-        if c == Coverage::Covered {
-            coverage += 1;
-        }
-    });
+    // Determine the cover for the row, excluding any known sensors or beacons (part 1)
+    let row = config.row;
+    let row_coverage = calc_row_coverage(&sensors, row);
+    let occupied = sensors
+        .iter()
+        .flat_map(|s| [s.location, s.beacon])
+        .filter(|pt| pt.y == row && row_coverage.contains(pt.x))
+        .collect::<HashSet<Point>>()
+        .len();
+    let coverage = usize::try_from(row_coverage.covered_length()).unwrap() - occupied;
 
     println!("[Part I] In row {row}, there are {coverage} position which cannot contain a beacon");
+
+    // Find the only position within the search area that no sensor covers (part 2)
+    let bound = config.search_bound;
+    let Some(beacon) = find_distress_beacon(&sensors, bound) else {
+        panic!("Unable to find the distress beacon within 0..={bound}!");
+    };
+
+    let tuning_frequency = i64::from(beacon.x) * TUNING_MULTIPLIER + i64::from(beacon.y);
+    println!("[Part II] The distress beacon is at x={}, y={} with a tuning frequency of {tuning_frequency}", beacon.x, beacon.y);
     Ok(())
 }
 
-// For a given row, determine the coverage for each cell and call the iterator function
-#[allow(clippy::cast_sign_loss)]
-fn iter_row_coverage<F>(sensors: &[Sensor], y: i32, mut f: F)
-where
-    F: FnMut(Point, Coverage),
-{
-    // Get the left-most sensor by (X - range)
-    let leftmost_sensor = sensors
-        .iter()
-        .min_by(|&s1, &s2| {
-            let s1_total = s1.location.x - s1.distance_to_beacon();
-            let s2_total = s2.location.x - s2.distance_to_beacon();
-            s1_total.cmp(&s2_total)
-        })
-        .unwrap();
-
-    // Get the right-most sensor by (X + range)
-    let rightmost_sensor = sensors
+// For a given row, determine the X ranges covered by any sensor
+fn calc_row_coverage(sensors: &[Sensor], y: i32) -> IntervalSet {
+    sensors
         .iter()
-        .max_by(|&s1, &s2| {
-            let s1_total = s1.location.x + s1.distance_to_beacon();
-            let s2_total = s2.location.x + s2.distance_to_beacon();
-            s1_total.cmp(&s2_total)
-        })
-        .unwrap();
-
-    // Determine the X range based on the left and right sensors
-    let min_x = leftmost_sensor.location.x - leftmost_sensor.distance_to_beacon();
-    let max_x = rightmost_sensor.location.x + rightmost_sensor.distance_to_beacon();
-
-    for x in min_x..=max_x {
-        let pt = Point::new(x, y);
-
-        // Check if there is a sensor on this space
-        if sensors
-            .iter()
-            .any(|s| s.location.x == x && s.location.y == y)
-        {
-            f(pt, Coverage::Sensor);
-            continue;
-        }
-
-        // Check if there is a beacon on this space
-        if sensors.iter().any(|s| s.beacon.x == x && s.beacon.y == y) {
-            f(pt, Coverage::Beacon);
-            continue;
-        }
-
-        // Check if there is a sensor within range of this
-        if sensors.iter().any(|s| {
-            let distance = manhattan_distance(s.location.x, s.location.y, x, y);
-            distance <= s.distance_to_beacon() as u32
-        }) {
-            f(pt, Coverage::Covered);
-        } else {
-            f(pt, Coverage::Uncovered);
-        }
-    }
+        .filter_map(|s| s.coverage().row_intersection(y))
+        .collect()
+}
+
+// Scans each row of the search area for a gap in the sensor coverage
+fn find_distress_beacon(sensors: &[Sensor], bound: i32) -> Option<Point> {
+    let search_area = Interval::new(0, bound);
+
+    (0..=bound).find_map(|y| {
+        let row_coverage = calc_row_coverage(sensors, y);
+        let gap = row_coverage.gaps(&search_area).into_iter().next()?;
+        Some(Point::new(gap.start, y))
+    })
 }
 
 // Reads the sensor and beacon data file into a vector of sensor data
-fn read_sensors(reader: &mut impl BufRead) -> Vec<Sensor> {
+fn read_sensors(reader: &mut impl BufRead) -> Result<Vec<Sensor>, PointParseError> {
     let mut sensors = Vec::with_capacity(100);
 
     // Read each line as a scan trace, skipping empty lines
@@ -147,51 +146,21 @@ fn read_sensors(reader: &mut impl BufRead) -> Vec<Sensor> {
             .unwrap_or_else(|| panic!("Invalid sensor line: {line}"));
 
         // Attempt to parse the sensor location part
-        let location = parse_point(sensor_str)
-            .unwrap_or_else(|| panic!("Invalid sensor location: {sensor_str}"));
+        let location = parse_location(sensor_str)?;
 
         // Attempt to parse the beacon location part
-        let beacon = parse_point(beacon_str)
-            .unwrap_or_else(|| panic!("Invalid beacon location: {beacon_str}"));
+        let beacon = parse_location(beacon_str)?;
 
         sensors.push(Sensor { location, beacon });
     }
 
-    sensors
+    Ok(sensors)
 }
 
-// Attempts to parse a point from the slice, assuming both `x=` and `y=` are present
-fn parse_point(slice: &str) -> Option<Point> {
-    let mut x: Option<i32> = None;
-    let mut y: Option<i32> = None;
-
-    // Used to trim out non-numeric characters
-    let is_not_numeric = |c| !char::is_numeric(c);
-
-    // Start by splitting by whitespace to find the expression
-    for part in slice.split_whitespace() {
-        // Try to split by the equal sign to see if we have an expression
-        if let Some((lhs, rhs)) = part.split_once('=') {
-            // If the left-hand side is X or Y, attempt to parse as a number
-            match lhs {
-                "x" => {
-                    if let Ok(value) = rhs.trim_matches(is_not_numeric).parse::<i32>() {
-                        x.replace(value);
-                    }
-                }
-                "y" => {
-                    if let Ok(value) = rhs.trim_matches(is_not_numeric).parse::<i32>() {
-                        y.replace(value);
-                    }
-                }
-                _ => continue,
-            }
-        }
-    }
-
-    // If both X and Y were found, return a point
-    match (x, y) {
-        (Some(x), Some(y)) => Some(Point::new(x, y)),
-        _ => None,
+// Parses the point following the word `at`, such as "Sensor at x=2, y=18"
+fn parse_location(slice: &str) -> Result<Point, PointParseError> {
+    match slice.split_once(" at ") {
+        Some((_, point_str)) => point_str.parse(),
+        None => slice.parse(),
     }
 }
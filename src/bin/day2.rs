@@ -40,6 +40,7 @@ impl Outcome {
     }
 }
 
+#[allow(clippy::struct_field_names)]
 struct Round {
     their_shape: Shape,
     your_shape: Shape,
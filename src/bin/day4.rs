@@ -1,24 +1,21 @@
 #![warn(clippy::pedantic)]
+use advent_of_rust_2022::Interval;
 use std::error::Error;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 
-struct SectorRange {
-    start: u32,
-    end: u32,
-}
+type SectorRange = Interval<u32>;
 
-impl SectorRange {
-    pub fn parse(string: &str) -> Option<Self> {
-        let (start, end) = string.split_once('-')?;
-        let Ok(start) = start.parse::<u32>() else {
-            return None;
-        };
-        let Ok(end) = end.parse::<u32>() else {
-            return None;
-        };
-        Some(SectorRange { start, end })
-    }
+// Attempts to parse a sector range in the form of `start-end`
+fn parse_sector_range(string: &str) -> Option<SectorRange> {
+    let (start, end) = string.split_once('-')?;
+    let Ok(start) = start.parse::<u32>() else {
+        return None;
+    };
+    let Ok(end) = end.parse::<u32>() else {
+        return None;
+    };
+    Some(SectorRange::new(start, end))
 }
 
 struct Assignment(SectorRange, SectorRange);
@@ -43,12 +40,12 @@ fn main() -> Result<(), Box<dyn Error>> {
         };
 
         // Attempt to parse the first range, skip if invalid
-        let Some(first_range) = SectorRange::parse(first_range) else {
+        let Some(first_range) = parse_sector_range(first_range) else {
             continue;
         };
 
         // Attempt to parse the second range, skip if invalid
-        let Some(second_range) = SectorRange::parse(second_range) else {
+        let Some(second_range) = parse_sector_range(second_range) else {
             continue;
         };
 
@@ -59,13 +56,13 @@ fn main() -> Result<(), Box<dyn Error>> {
     // Determine the number of fully overlapped assignments (part 1)
     let fully_overlapped_count = assignments
         .iter()
-        .filter(|a| is_fully_overlapped(&a.0, &a.1))
+        .filter(|a| is_fully_overlapped(a.0, a.1))
         .count();
 
     // Determine the number of partially overlapped assignments (part 2)
     let partially_overlapped_count = assignments
         .iter()
-        .filter(|a| is_partially_overlapped(&a.0, &a.1))
+        .filter(|a| is_partially_overlapped(a.0, a.1))
         .count();
 
     println!("[Part I] There are {fully_overlapped_count} fully overlapped assignments");
@@ -74,15 +71,11 @@ fn main() -> Result<(), Box<dyn Error>> {
 }
 
 // Determines if either A or B can fully overlap one another
-fn is_fully_overlapped(a: &SectorRange, b: &SectorRange) -> bool {
-    (a.start >= b.start && a.end <= b.end) || (b.start >= a.start && b.end <= a.end)
+fn is_fully_overlapped(a: SectorRange, b: SectorRange) -> bool {
+    a.contains_interval(&b) || b.contains_interval(&a)
 }
 
 // Determines if either A or B are partially overlapped
-fn is_partially_overlapped(a: &SectorRange, b: &SectorRange) -> bool {
-    // Ranges overlap if there exists a number 'N' which exists in both ranges:
-    // x1 <= N <= x2  AND  y1 <= N <= y2
-    // Can also be written as:
-    // x1 <= y2 && y1 <= x2
-    (a.start <= b.end) && (a.end >= b.start)
+fn is_partially_overlapped(a: SectorRange, b: SectorRange) -> bool {
+    a.overlaps(&b)
 }
@@ -89,7 +89,7 @@ fn read_initial_stacks(reader: &mut impl BufRead) -> Vec<Vec<String>> {
             // Build the stack from the "top-down", newer entries are last out
             // We only accept alphabetic values, ignoring the stack "number" line
             if char.is_ascii_alphabetic() {
-                let _ = &stacks[stack_index].insert(0, char.to_string());
+                stacks[stack_index].insert(0, char.to_string());
             }
         }
 
@@ -59,7 +59,7 @@ fn find_start_marker(data: &[u8], header_size: usize) -> Option<usize> {
 }
 
 // Determines if all items within a vector are unique
-fn all_are_unique(data: &Vec<u8>) -> bool {
+fn all_are_unique(data: &[u8]) -> bool {
     for i in 0..data.len() {
         for j in 0..data.len() {
             if i == j {
@@ -50,7 +50,7 @@ fn read_tree_grid(reader: &mut impl BufRead) -> RowGrid<u8> {
 
         // For each digit on the line, add it as a height value to the grid
         // Since ASCII digits are 0x30 through 0x39 we can just modulo to get the value
-        let row: Vec<u8> = line.bytes().map(|digit| (digit % 0x30)).collect();
+        let row: Vec<u8> = line.bytes().map(|digit| digit % 0x30).collect();
 
         grid.push_row(row);
     }
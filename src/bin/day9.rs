@@ -1,30 +1,17 @@
 #![warn(clippy::pedantic)]
-use advent_of_rust_2022::{manhattan_distance, ArenaLinkedList};
+use advent_of_rust_2022::{manhattan_distance, ArenaLinkedList, Direction, Point};
 use std::error::Error;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
 
-#[derive(Debug, Copy, Clone)]
-enum Direction {
-    North,
-    South,
-    East,
-    West,
-    Northeast,
-    Northwest,
-    Southeast,
-    Southwest,
-}
-
 #[derive(Debug)]
 struct MoveSpaces(Direction, i32);
 
 // Represents a point that keeps track of where it has been previously
 #[derive(Debug)]
 struct PointHistory {
-    x: i32,
-    y: i32,
-    visited: Vec<(i32, i32)>,
+    position: Point,
+    visited: Vec<Point>,
 }
 
 impl Default for PointHistory {
@@ -35,30 +22,18 @@ impl Default for PointHistory {
 
 impl PointHistory {
     pub fn with_initial(x: i32, y: i32) -> Self {
+        let position = Point::new(x, y);
         Self {
-            x,
-            y,
-            visited: vec![(x, y)],
+            position,
+            visited: vec![position],
         }
     }
 
     pub fn move_dir(&mut self, direction: Direction) {
-        let new_pos = match direction {
-            Direction::North => (self.x, self.y + 1),
-            Direction::South => (self.x, self.y - 1),
-            Direction::East => (self.x + 1, self.y),
-            Direction::West => (self.x - 1, self.y),
-            Direction::Northeast => (self.x + 1, self.y + 1),
-            Direction::Northwest => (self.x - 1, self.y + 1),
-            Direction::Southeast => (self.x + 1, self.y - 1),
-            Direction::Southwest => (self.x - 1, self.y - 1),
-        };
+        self.position = self.position.step(direction);
 
-        self.x = new_pos.0;
-        self.y = new_pos.1;
-
-        if !self.visited.contains(&new_pos) {
-            self.visited.push(new_pos);
+        if !self.visited.contains(&self.position) {
+            self.visited.push(self.position);
         }
     }
 }
@@ -149,86 +124,52 @@ fn simulate_movement(movement: &MoveSpaces, rope: &mut ArenaLinkedList<PointHist
 
     // Move the head and then move all following nodes relatively to their previous
     // H <- 1 <- 2 <- 3 <- 4 ... < TAIL
+    let Some(head_index) = rope.head().map(|node| node.index) else {
+        return;
+    };
+
     for _ in 0..spaces {
         // The head moves based on the explicit movement
-        let head = rope.get_mut(0).unwrap();
+        let head = rope.get_mut(head_index).unwrap();
         head.move_dir(dir);
 
-        for i in 0..rope.len() {
+        // Walk the links from the head, each node following the one before it
+        let mut leader_index = head_index;
+        while let Some(node) = rope.node(leader_index) {
             // We only need the leader's position for the follower
-            let leader_pos = match rope.get(i) {
-                Some(node) => (node.x, node.y),
-                None => break,
-            };
-
-            // We need a mutable reference to the follower so we can move them
-            let Some(follower) = rope.get_mut(i + 1) else {
+            let leader_pos = node.value.position;
+            let Some(follower_index) = node.next else {
                 break;
             };
 
-            follow_the_leader(follower, leader_pos.0, leader_pos.1);
+            // We need a mutable reference to the follower so we can move them
+            let follower = rope.get_mut(follower_index).unwrap();
+            follow_the_leader(follower, leader_pos);
+            leader_index = follower_index;
         }
     }
 }
 
 // Moves the follower node towards the leader according to the follow distance rules
-fn follow_the_leader(follower: &mut PointHistory, leader_x: i32, leader_y: i32) {
+fn follow_the_leader(follower: &mut PointHistory, leader: Point) {
+    let follower_pos = follower.position;
+
     // If not on the same row or column, there is a diagonal distance
-    let diagonal = leader_x != follower.x && leader_y != follower.y;
+    let diagonal = leader.x != follower_pos.x && leader.y != follower_pos.y;
 
     // If the follower is only a single space behind (including diagonal), do not move it
     // Otherwise we have to move the follower based on whether it is diagonal or not
-    let distance = manhattan_distance(leader_x, leader_y, follower.x, follower.y);
+    let distance = manhattan_distance(leader.x, leader.y, follower_pos.x, follower_pos.y);
     let max_distance = if diagonal { 3 } else { 2 };
     if distance < max_distance {
         return;
     }
 
-    // Determine which horizontal direction we should move the follower (if any)
-    let h_dir = match leader_x - follower.x {
-        dx if dx.unsigned_abs() > 0 => {
-            if dx > 0 {
-                Some(Direction::East)
-            } else {
-                Some(Direction::West)
-            }
-        }
-        _ => None,
-    };
-
-    // Determine which vertical direction we should move the follower (if any)
-    let v_dir = match leader_y - follower.y {
-        dy if dy.unsigned_abs() > 0 => {
-            if dy > 0 {
-                Some(Direction::North)
-            } else {
-                Some(Direction::South)
-            }
-        }
-        _ => None,
-    };
-
-    // Combine directions for both horizontal and vertical movement
-    let move_dir = match (h_dir, v_dir) {
-        (Some(h), None) => Some(h),
-        (None, Some(v)) => Some(v),
-        (Some(h), Some(v)) => Some(combine_dir(h, v)),
-        _ => None,
-    };
+    // Move one space towards the leader, combining horizontal and vertical movement
+    let move_dir = Direction::from_offset((leader - follower_pos).signum());
 
     // If the follower needs to move, do so
     if let Some(dir) = move_dir {
         follower.move_dir(dir);
     }
 }
-
-// Determine a new direction based on combining a horizontal and vertical direction together
-fn combine_dir(horizontal: Direction, vertical: Direction) -> Direction {
-    match (horizontal, vertical) {
-        (Direction::East, Direction::North) => Direction::Northeast,
-        (Direction::East, Direction::South) => Direction::Southeast,
-        (Direction::West, Direction::North) => Direction::Northwest,
-        (Direction::West, Direction::South) => Direction::Southwest,
-        _ => panic!("Invalid combination of directions"),
-    }
-}
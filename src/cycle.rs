@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// Long-running simulations (monkey rounds, falling rocks, etc) eventually repeat themselves,
+// so once a cycle is found any far-off step can be extrapolated instead of simulated
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    #[must_use]
+    pub fn new(start: usize, length: usize) -> Self {
+        Self { start, length }
+    }
+
+    // Maps any step to the equivalent step within the first occurrence of the cycle
+    #[must_use]
+    pub fn equivalent_step(&self, step: usize) -> usize {
+        if step < self.start {
+            step
+        } else {
+            self.start + (step - self.start) % self.length
+        }
+    }
+
+    // Extrapolates a cumulative metric to an arbitrary step, where `history[n]` is the metric
+    // after `n` steps and contains at least one full cycle (`start + length + 1` entries)
+    #[must_use]
+    pub fn extrapolate(&self, history: &[i64], step: usize) -> i64 {
+        let cycle_end = self.start + self.length;
+        assert!(
+            history.len() > cycle_end,
+            "History must contain at least {} entries to extrapolate!",
+            cycle_end + 1
+        );
+
+        if step < history.len() {
+            return history[step];
+        }
+
+        // Each full cycle adds the same amount to the metric
+        let per_cycle = history[cycle_end] - history[self.start];
+        let offset = step - self.start;
+        let full_cycles = i64::try_from(offset / self.length).unwrap();
+
+        history[self.start + offset % self.length] + full_cycles * per_cycle
+    }
+}
+
+// Implements Floyd's "tortoise and hare" cycle detection, comparing states by the user key
+// The next state must only depend on the key, otherwise the detected cycle may be wrong
+pub fn floyd_cycle<S, K, F, G>(initial: &S, step: F, key: G) -> Cycle
+where
+    S: Clone,
+    K: PartialEq,
+    F: Fn(&S) -> S,
+    G: Fn(&S) -> K,
+{
+    // Find a meeting point within the cycle, where the hare moves twice as fast
+    let mut tortoise = step(initial);
+    let mut hare = step(&step(initial));
+    while key(&tortoise) != key(&hare) {
+        tortoise = step(&tortoise);
+        hare = step(&step(&hare));
+    }
+
+    // Restart the tortoise, and move both at the same speed to find the cycle start
+    let mut start = 0;
+    tortoise = initial.clone();
+    while key(&tortoise) != key(&hare) {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    // Walk the hare around the cycle once to determine the length
+    let mut length = 1;
+    hare = step(&tortoise);
+    while key(&tortoise) != key(&hare) {
+        hare = step(&hare);
+        length += 1;
+    }
+
+    Cycle::new(start, length)
+}
+
+// Implements Brent's cycle detection, which usually requires fewer steps than Floyd's
+// The next state must only depend on the key, otherwise the detected cycle may be wrong
+pub fn brent_cycle<S, K, F, G>(initial: &S, step: F, key: G) -> Cycle
+where
+    S: Clone,
+    K: PartialEq,
+    F: Fn(&S) -> S,
+    G: Fn(&S) -> K,
+{
+    // Search successive powers of two until the hare catches the tortoise
+    let mut power = 1;
+    let mut length = 1;
+    let mut tortoise = initial.clone();
+    let mut hare = step(initial);
+    while key(&tortoise) != key(&hare) {
+        if power == length {
+            tortoise = hare.clone();
+            power *= 2;
+            length = 0;
+        }
+        hare = step(&hare);
+        length += 1;
+    }
+
+    // Move the hare one cycle length ahead, then move both together to find the cycle start
+    let mut start = 0;
+    tortoise = initial.clone();
+    hare = initial.clone();
+    for _ in 0..length {
+        hare = step(&hare);
+    }
+    while key(&tortoise) != key(&hare) {
+        tortoise = step(&tortoise);
+        hare = step(&hare);
+        start += 1;
+    }
+
+    Cycle::new(start, length)
+}
+
+// Hash map based cycle detection that is fed one step at a time from a running simulation,
+// recording the cumulative metric at each step so it can be extrapolated later
+#[derive(Debug)]
+pub struct CycleDetector<K> {
+    seen: HashMap<K, usize>,
+    history: Vec<i64>,
+    cycle: Option<Cycle>,
+}
+
+impl<K> Default for CycleDetector<K> {
+    fn default() -> Self {
+        Self {
+            seen: HashMap::new(),
+            history: Vec::new(),
+            cycle: None,
+        }
+    }
+}
+
+impl<K> CycleDetector<K>
+where
+    K: Eq + Hash,
+{
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // Gets the cycle, if one has been detected
+    #[must_use]
+    pub fn cycle(&self) -> Option<Cycle> {
+        self.cycle
+    }
+
+    // Gets the cumulative metric recorded at each step so far
+    #[must_use]
+    pub fn history(&self) -> &[i64] {
+        &self.history
+    }
+
+    // Records the state key and cumulative metric for the next step, returning the cycle once found
+    pub fn observe(&mut self, key: K, metric: i64) -> Option<Cycle> {
+        if self.cycle.is_some() {
+            return self.cycle;
+        }
+
+        let step = self.history.len();
+        self.history.push(metric);
+
+        if let Some(&previous) = self.seen.get(&key) {
+            self.cycle = Some(Cycle::new(previous, step - previous));
+        } else {
+            self.seen.insert(key, step);
+        }
+
+        self.cycle
+    }
+
+    // Extrapolates the cumulative metric to an arbitrary step, if a cycle has been detected
+    #[must_use]
+    pub fn extrapolate(&self, step: usize) -> Option<i64> {
+        self.cycle
+            .map(|cycle| cycle.extrapolate(&self.history, step))
+    }
+}
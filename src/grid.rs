@@ -1,3 +1,5 @@
+use crate::{polyline_points, Point};
+
 // 2D grid that can be used when you have a known column width
 // Rows can be added later, but must be of uniform size
 
@@ -153,6 +155,17 @@ where
         self.cells[y * self.width + x] = value;
     }
 
+    // Sets every cell along a chain of line segments, ignoring any points outside the grid
+    pub fn draw_polyline(&mut self, points: &[Point], value: &T) {
+        for point in polyline_points(points) {
+            if let Some((x, y)) = point.to_grid() {
+                if x < self.width && y < self.height {
+                    self.set_cell(x, y, value.clone());
+                }
+            }
+        }
+    }
+
     #[must_use]
     pub fn find_all<F>(&self, predicate: F) -> Vec<(usize, usize)>
     where
@@ -0,0 +1,323 @@
+use crate::Coordinate;
+use std::fmt::{Display, Formatter};
+use std::ops::RangeInclusive;
+
+// Represents an inclusive range of integers, where the start is never after the end
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Interval<T = i32> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T> Interval<T>
+where
+    T: Coordinate,
+{
+    // Creates the interval spanning both values, regardless of which order they are given
+    #[must_use]
+    pub fn new(a: T, b: T) -> Self {
+        Self {
+            start: a.min(b),
+            end: a.max(b),
+        }
+    }
+
+    // Creates an interval covering a single value
+    #[must_use]
+    pub fn single(value: T) -> Self {
+        Self {
+            start: value,
+            end: value,
+        }
+    }
+
+    // Gets the number of values within the interval
+    // Like the primitive arithmetic, this overflows if the count does not fit within the type,
+    // such as an interval spanning every value (use a wider type for the interval if needed)
+    #[must_use]
+    pub fn len(&self) -> T {
+        self.end - self.start + T::one()
+    }
+
+    #[must_use]
+    pub fn contains(&self, value: T) -> bool {
+        self.start <= value && value <= self.end
+    }
+
+    // Determines if the other interval is entirely within this one
+    #[must_use]
+    pub fn contains_interval(&self, other: &Interval<T>) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    // Determines if there exists any value which is within both intervals
+    #[must_use]
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+
+    // Determines if the intervals do not overlap, but have no gap between them either
+    #[must_use]
+    pub fn is_adjacent(&self, other: &Interval<T>) -> bool {
+        // Adding to the lower end cannot overflow, since it is below the other start
+        (self.end < other.start && self.end + T::one() == other.start)
+            || (other.end < self.start && other.end + T::one() == self.start)
+    }
+
+    // Gets the values shared by both intervals, or None if they do not overlap
+    #[must_use]
+    pub fn intersection(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        if self.overlaps(other) {
+            Some(Interval {
+                start: self.start.max(other.start),
+                end: self.end.min(other.end),
+            })
+        } else {
+            None
+        }
+    }
+
+    // Combines both intervals into one, or None if there would be a gap between them
+    #[must_use]
+    pub fn merge(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        if self.overlaps(other) || self.is_adjacent(other) {
+            Some(self.hull(other))
+        } else {
+            None
+        }
+    }
+
+    // Gets the smallest interval that contains both intervals (including any gap)
+    #[must_use]
+    pub fn hull(&self, other: &Interval<T>) -> Interval<T> {
+        Interval {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        }
+    }
+
+    // Iterates every value within the interval
+    pub fn values(&self) -> impl Iterator<Item = T> {
+        let end = self.end;
+        std::iter::successors(Some(self.start), move |&value| {
+            if value < end {
+                Some(value + T::one())
+            } else {
+                None
+            }
+        })
+    }
+}
+
+impl<T> From<RangeInclusive<T>> for Interval<T>
+where
+    T: Coordinate,
+{
+    fn from(range: RangeInclusive<T>) -> Self {
+        let (start, end) = range.into_inner();
+        Self::new(start, end)
+    }
+}
+
+impl<T> From<Interval<T>> for RangeInclusive<T> {
+    fn from(interval: Interval<T>) -> Self {
+        interval.start..=interval.end
+    }
+}
+
+impl<T> Display for Interval<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+// A set of integers stored as sorted intervals, which never overlap or touch one another
+// Much faster than tracking individual values when the ranges span millions of values
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct IntervalSet<T = i32> {
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T> IntervalSet<T>
+where
+    T: Coordinate,
+{
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            intervals: Vec::new(),
+        }
+    }
+
+    // Gets the normalized intervals, sorted from lowest to highest
+    #[must_use]
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Interval<T>> {
+        self.intervals.iter()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    // Gets the total number of values covered by all intervals
+    // Overflows if the total does not fit within the type, the same as `Interval::len`
+    #[must_use]
+    pub fn covered_length(&self) -> T {
+        self.intervals
+            .iter()
+            .fold(T::zero(), |total, interval| total + interval.len())
+    }
+
+    #[must_use]
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.intervals.partition_point(|iv| iv.end < value);
+        self.intervals
+            .get(index)
+            .is_some_and(|iv| iv.contains(value))
+    }
+
+    // Adds the interval to the set, merging it with any overlapping or adjacent intervals
+    pub fn insert(&mut self, interval: Interval<T>) {
+        // Find the run of existing intervals that touch the new one
+        let left = self
+            .intervals
+            .partition_point(|iv| iv.end < interval.start && !iv.is_adjacent(&interval));
+        let right = self
+            .intervals
+            .partition_point(|iv| iv.start <= interval.end || iv.is_adjacent(&interval));
+
+        let merged = self.intervals[left..right]
+            .iter()
+            .fold(interval, |merged, iv| merged.hull(iv));
+
+        self.intervals.splice(left..right, [merged]);
+    }
+
+    // Removes every value within the interval from the set, splitting intervals as needed
+    pub fn subtract(&mut self, interval: &Interval<T>) {
+        let left = self.intervals.partition_point(|iv| iv.end < interval.start);
+        let right = self
+            .intervals
+            .partition_point(|iv| iv.start <= interval.end);
+
+        let mut remaining = Vec::with_capacity(2);
+        for iv in &self.intervals[left..right] {
+            if iv.start < interval.start {
+                remaining.push(Interval::new(iv.start, interval.start - T::one()));
+            }
+            if iv.end > interval.end {
+                remaining.push(Interval::new(interval.end + T::one(), iv.end));
+            }
+        }
+
+        self.intervals.splice(left..right, remaining);
+    }
+
+    // Removes every value within the other set from this set
+    pub fn subtract_set(&mut self, other: &IntervalSet<T>) {
+        for interval in &other.intervals {
+            self.subtract(interval);
+        }
+    }
+
+    // Gets a set containing the values found in both sets
+    #[must_use]
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut result = IntervalSet::new();
+        let (mut i, mut j) = (0, 0);
+
+        // Walk both sorted lists together, advancing whichever interval ends first
+        while i < self.intervals.len() && j < other.intervals.len() {
+            let a = &self.intervals[i];
+            let b = &other.intervals[j];
+
+            if let Some(shared) = a.intersection(b) {
+                result.intervals.push(shared);
+            }
+
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        result
+    }
+
+    // Gets a set containing the values found in either set
+    #[must_use]
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut result = self.clone();
+        for interval in &other.intervals {
+            result.insert(*interval);
+        }
+        result
+    }
+
+    // Gets the intervals within the bounds that are not covered by this set
+    #[must_use]
+    pub fn gaps(&self, bounds: &Interval<T>) -> Vec<Interval<T>> {
+        let mut gaps = Vec::new();
+        let mut cursor = bounds.start;
+
+        for interval in &self.intervals {
+            if interval.end < cursor {
+                continue;
+            }
+            if interval.start > bounds.end {
+                break;
+            }
+
+            if interval.start > cursor {
+                gaps.push(Interval::new(cursor, interval.start - T::one()));
+            }
+
+            // Nothing else can be uncovered once an interval reaches the end of the bounds
+            if interval.end >= bounds.end {
+                return gaps;
+            }
+            cursor = interval.end + T::one();
+        }
+
+        gaps.push(Interval::new(cursor, bounds.end));
+        gaps
+    }
+}
+
+impl<T> FromIterator<Interval<T>> for IntervalSet<T>
+where
+    T: Coordinate,
+{
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T> Extend<Interval<T>> for IntervalSet<T>
+where
+    T: Coordinate,
+{
+    fn extend<I: IntoIterator<Item = Interval<T>>>(&mut self, iter: I) {
+        // Sort and merge in a single pass, rather than inserting one at a time
+        let mut intervals: Vec<Interval<T>> = self.intervals.drain(..).chain(iter).collect();
+        intervals.sort_unstable_by_key(|iv| iv.start);
+
+        for interval in intervals {
+            match self.intervals.last_mut() {
+                Some(last) if last.merge(&interval).is_some() => *last = last.hull(&interval),
+                _ => self.intervals.push(interval),
+            }
+        }
+    }
+}
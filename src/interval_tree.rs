@@ -0,0 +1,316 @@
+// Interval trees answer "which ranges contain this value?" and "which ranges overlap this one?"
+// without checking every range, by augmenting a balanced tree with the max end of each subtree
+
+// Like the other arena structures, nodes are stored in a vector and linked by index
+// Rebalancing only relinks nodes rather than moving them, so the index returned by insert
+// refers to the same interval until it is removed
+#[derive(Debug)]
+pub struct ArenaIntervalTree<K, V> {
+    nodes: Vec<Option<IntervalTreeNode<K, V>>>,
+    free: Vec<usize>,
+    root: Option<usize>,
+    len: usize,
+}
+
+#[derive(Debug)]
+pub struct IntervalTreeNode<K, V> {
+    pub index: usize,
+    pub start: K,
+    pub end: K,
+    pub value: V,
+    left: Option<usize>,
+    right: Option<usize>,
+    max_end: K,
+    height: u32,
+}
+
+impl<K, V> Default for ArenaIntervalTree<K, V> {
+    fn default() -> Self {
+        Self {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            root: None,
+            len: 0,
+        }
+    }
+}
+
+impl<K, V> ArenaIntervalTree<K, V>
+where
+    K: Ord + Copy,
+{
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // Gets the node at the index, or None if it has been removed
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&IntervalTreeNode<K, V>> {
+        self.nodes.get(index)?.as_ref()
+    }
+
+    // Gets a mutable reference to the value at the index, or None if it has been removed
+    #[must_use]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut V> {
+        Some(&mut self.nodes.get_mut(index)?.as_mut()?.value)
+    }
+
+    // Inserts the inclusive interval with an associated value, returning the index of the new node
+    pub fn insert(&mut self, start: K, end: K, value: V) -> usize {
+        let (start, end) = (start.min(end), start.max(end));
+
+        // Reuse a previously removed slot, if there is one
+        let index = self.free.pop().unwrap_or(self.nodes.len());
+        let node = IntervalTreeNode {
+            index,
+            start,
+            end,
+            value,
+            left: None,
+            right: None,
+            max_end: end,
+            height: 1,
+        };
+
+        if index == self.nodes.len() {
+            self.nodes.push(Some(node));
+        } else {
+            self.nodes[index] = Some(node);
+        }
+
+        self.root = Some(self.insert_at(self.root, index));
+        self.len += 1;
+        index
+    }
+
+    // Removes the node at the index, returning its value (if it existed)
+    pub fn remove(&mut self, index: usize) -> Option<V> {
+        self.get(index)?;
+
+        self.root = self.remove_at(self.root, index);
+        self.len -= 1;
+        self.free.push(index);
+
+        self.nodes[index].take().map(|node| node.value)
+    }
+
+    // Gets every node whose interval contains the value
+    #[must_use]
+    pub fn stab(&self, value: K) -> Vec<&IntervalTreeNode<K, V>> {
+        self.find_overlapping(value, value)
+    }
+
+    // Gets every node whose interval shares at least one value with the inclusive range
+    #[must_use]
+    pub fn find_overlapping(&self, start: K, end: K) -> Vec<&IntervalTreeNode<K, V>> {
+        let mut found = vec![];
+        self.collect_overlapping(self.root, start.min(end), start.max(end), &mut found);
+        found
+    }
+
+    // Traverses every node in order of interval start
+    pub fn traverse<F>(&self, mut func: F)
+    where
+        F: FnMut(&IntervalTreeNode<K, V>),
+    {
+        let mut stack = vec![];
+        let mut current = self.root;
+
+        while current.is_some() || !stack.is_empty() {
+            while let Some(index) = current {
+                stack.push(index);
+                current = self.node(index).left;
+            }
+
+            if let Some(index) = stack.pop() {
+                let node = self.node(index);
+                func(node);
+                current = node.right;
+            }
+        }
+    }
+
+    fn collect_overlapping<'a>(
+        &'a self,
+        subtree: Option<usize>,
+        start: K,
+        end: K,
+        found: &mut Vec<&'a IntervalTreeNode<K, V>>,
+    ) {
+        // Nothing in this subtree reaches far enough to overlap
+        let Some(index) = subtree else {
+            return;
+        };
+        let node = self.node(index);
+        if node.max_end < start {
+            return;
+        }
+
+        self.collect_overlapping(node.left, start, end, found);
+
+        // Everything to the right starts after this node, so it may be too far as well
+        if node.start <= end {
+            if node.end >= start {
+                found.push(node);
+            }
+            self.collect_overlapping(node.right, start, end, found);
+        }
+    }
+
+    fn node(&self, index: usize) -> &IntervalTreeNode<K, V> {
+        self.nodes[index].as_ref().unwrap()
+    }
+
+    fn node_mut(&mut self, index: usize) -> &mut IntervalTreeNode<K, V> {
+        self.nodes[index].as_mut().unwrap()
+    }
+
+    // Nodes are ordered by interval, using the index to break ties between equal intervals
+    fn key(&self, index: usize) -> (K, K, usize) {
+        let node = self.node(index);
+        (node.start, node.end, index)
+    }
+
+    fn height(&self, subtree: Option<usize>) -> u32 {
+        subtree.map_or(0, |index| self.node(index).height)
+    }
+
+    fn insert_at(&mut self, subtree: Option<usize>, new_index: usize) -> usize {
+        let Some(index) = subtree else {
+            return new_index;
+        };
+
+        if self.key(new_index) < self.key(index) {
+            let left = self.insert_at(self.node(index).left, new_index);
+            self.node_mut(index).left = Some(left);
+        } else {
+            let right = self.insert_at(self.node(index).right, new_index);
+            self.node_mut(index).right = Some(right);
+        }
+
+        self.rebalance(index)
+    }
+
+    fn remove_at(&mut self, subtree: Option<usize>, target: usize) -> Option<usize> {
+        let index = subtree?;
+
+        match self.key(target).cmp(&self.key(index)) {
+            std::cmp::Ordering::Less => {
+                let left = self.remove_at(self.node(index).left, target);
+                self.node_mut(index).left = left;
+            }
+            std::cmp::Ordering::Greater => {
+                let right = self.remove_at(self.node(index).right, target);
+                self.node_mut(index).right = right;
+            }
+            std::cmp::Ordering::Equal => {
+                let node = self.node(index);
+                return match (node.left, node.right) {
+                    (None, None) => None,
+                    (Some(child), None) | (None, Some(child)) => Some(child),
+                    (Some(left), Some(right)) => {
+                        // Replace the removed node with the smallest node of the right subtree
+                        let (right, successor) = self.remove_min(right);
+                        let successor_node = self.node_mut(successor);
+                        successor_node.left = Some(left);
+                        successor_node.right = right;
+                        Some(self.rebalance(successor))
+                    }
+                };
+            }
+        }
+
+        Some(self.rebalance(index))
+    }
+
+    // Detaches the smallest node of the subtree, returning the new subtree root and the detached node
+    fn remove_min(&mut self, index: usize) -> (Option<usize>, usize) {
+        let node = self.node(index);
+        let Some(left) = node.left else {
+            return (node.right, index);
+        };
+
+        let (left, min) = self.remove_min(left);
+        self.node_mut(index).left = left;
+        (Some(self.rebalance(index)), min)
+    }
+
+    // Recalculates the height and max end of the node from its children
+    fn update(&mut self, index: usize) {
+        let node = self.node(index);
+        let (left, right) = (node.left, node.right);
+
+        let height = 1 + self.height(left).max(self.height(right));
+        let max_end = [left, right]
+            .into_iter()
+            .flatten()
+            .map(|child| self.node(child).max_end)
+            .fold(node.end, K::max);
+
+        let node = self.node_mut(index);
+        node.height = height;
+        node.max_end = max_end;
+    }
+
+    // Restores the AVL balance of the subtree, returning the index of its (possibly new) root
+    fn rebalance(&mut self, index: usize) -> usize {
+        self.update(index);
+
+        let node = self.node(index);
+        let (left, right) = (node.left, node.right);
+        let (left_height, right_height) = (self.height(left), self.height(right));
+
+        if left_height > right_height + 1 {
+            let left = left.unwrap();
+            let left_node = self.node(left);
+            if self.height(left_node.left) < self.height(left_node.right) {
+                let rotated = self.rotate_left(left);
+                self.node_mut(index).left = Some(rotated);
+            }
+            return self.rotate_right(index);
+        }
+
+        if right_height > left_height + 1 {
+            let right = right.unwrap();
+            let right_node = self.node(right);
+            if self.height(right_node.right) < self.height(right_node.left) {
+                let rotated = self.rotate_right(right);
+                self.node_mut(index).right = Some(rotated);
+            }
+            return self.rotate_left(index);
+        }
+
+        index
+    }
+
+    fn rotate_left(&mut self, index: usize) -> usize {
+        let pivot = self.node(index).right.unwrap();
+        self.node_mut(index).right = self.node(pivot).left;
+        self.node_mut(pivot).left = Some(index);
+
+        self.update(index);
+        self.update(pivot);
+        pivot
+    }
+
+    fn rotate_right(&mut self, index: usize) -> usize {
+        let pivot = self.node(index).left.unwrap();
+        self.node_mut(index).left = self.node(pivot).right;
+        self.node_mut(pivot).right = Some(index);
+
+        self.update(index);
+        self.update(pivot);
+        pivot
+    }
+}
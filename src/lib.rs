@@ -4,12 +4,34 @@
 #![allow(clippy::module_name_repetitions)]
 mod astar;
 mod bigint;
+mod cycle;
 mod grid;
+mod interval;
+mod interval_tree;
+mod line;
 mod linked;
+mod manhattan;
+mod number_theory;
+mod point;
+mod property;
+mod rational;
+mod rect;
+mod search;
 mod tree;
 
 pub use astar::*;
 pub use bigint::*;
+pub use cycle::*;
 pub use grid::*;
+pub use interval::*;
+pub use interval_tree::*;
+pub use line::*;
 pub use linked::*;
+pub use manhattan::*;
+pub use number_theory::*;
+pub use point::*;
+pub use property::*;
+pub use rational::*;
+pub use rect::*;
+pub use search::*;
 pub use tree::*;
@@ -0,0 +1,125 @@
+use crate::Point;
+use std::iter::FusedIterator;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct LineSegment {
+    pub start: Point,
+    pub end: Point,
+}
+
+impl LineSegment {
+    #[must_use]
+    pub fn new(start: Point, end: Point) -> Self {
+        Self { start, end }
+    }
+
+    #[must_use]
+    pub fn is_horizontal(&self) -> bool {
+        self.start.y == self.end.y
+    }
+
+    #[must_use]
+    pub fn is_vertical(&self) -> bool {
+        self.start.x == self.end.x
+    }
+
+    #[must_use]
+    pub fn is_axis_aligned(&self) -> bool {
+        self.is_horizontal() || self.is_vertical()
+    }
+
+    // Determines if the segment is at exactly 45 degrees
+    #[must_use]
+    pub fn is_diagonal(&self) -> bool {
+        let width = self.start.x.abs_diff(self.end.x);
+        width != 0 && width == self.start.y.abs_diff(self.end.y)
+    }
+
+    // Gets every integer point on the segment, including both ends
+    #[must_use]
+    pub fn points(&self) -> LinePoints {
+        LinePoints::new(self.start, self.end)
+    }
+}
+
+// Iterates every integer point on a segment using Bresenham's line algorithm
+// Axis-aligned and 45 degree segments step exactly one space along each axis per point
+#[derive(Debug, Clone)]
+pub struct LinePoints {
+    current: Point,
+    step: Point,
+    delta_x: i64,
+    delta_y: i64,
+    error: i64,
+    remaining: usize,
+}
+
+impl LinePoints {
+    #[must_use]
+    pub fn new(start: Point, end: Point) -> Self {
+        // Subtract as i64, since the distance between two i32 values may not fit within an i32
+        let delta = Point::new(
+            i64::from(end.x) - i64::from(start.x),
+            i64::from(end.y) - i64::from(start.y),
+        );
+        let delta_x = delta.x.abs();
+        let delta_y = -delta.y.abs();
+
+        Self {
+            current: start,
+            step: delta.signum().try_cast().unwrap(),
+            delta_x,
+            delta_y,
+            error: delta_x + delta_y,
+            remaining: usize::try_from(delta_x.max(-delta_y)).unwrap() + 1,
+        }
+    }
+}
+
+impl Iterator for LinePoints {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let point = self.current;
+        self.remaining -= 1;
+
+        // Stop without stepping past the end, which may be at the edge of the i32 range
+        if self.remaining == 0 {
+            return Some(point);
+        }
+
+        // Move along whichever axes keep the error term closest to the true line
+        let doubled_error = self.error * 2;
+        if doubled_error >= self.delta_y {
+            self.error += self.delta_y;
+            self.current.x += self.step.x;
+        }
+        if doubled_error <= self.delta_x {
+            self.error += self.delta_x;
+            self.current.y += self.step.y;
+        }
+
+        Some(point)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl ExactSizeIterator for LinePoints {}
+
+impl FusedIterator for LinePoints {}
+
+// Gets every integer point along a chain of segments, without repeating the joining points
+pub fn polyline_points(points: &[Point]) -> impl Iterator<Item = Point> + '_ {
+    points.first().copied().into_iter().chain(
+        points
+            .windows(2)
+            .flat_map(|pair| LineSegment::new(pair[0], pair[1]).points().skip(1)),
+    )
+}
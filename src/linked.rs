@@ -1,9 +1,15 @@
 // Linked lists can be annoying in Rust with Box<T> or worse...
 // This uses a vector to store all linked nodes by index instead,
 // avoiding all the headaches of lifetimes and references
-#[derive(Debug, Default)]
+// Removing a node leaves an empty slot for the next insert to fill, so the other indices
+// never shift and can be kept as handles while the list is edited
+#[derive(Debug)]
 pub struct ArenaLinkedList<T> {
-    pub nodes: Vec<LinkedListNode<T>>,
+    nodes: Vec<Option<LinkedListNode<T>>>,
+    free: Vec<usize>,
+    head: Option<usize>,
+    tail: Option<usize>,
+    len: usize,
 }
 
 #[derive(Debug)]
@@ -14,16 +20,29 @@ pub struct LinkedListNode<T> {
     pub next: Option<usize>,
 }
 
+impl<T> Default for ArenaLinkedList<T> {
+    fn default() -> Self {
+        Self {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            head: None,
+            tail: None,
+            len: 0,
+        }
+    }
+}
+
 impl<T> ArenaLinkedList<T> {
     #[must_use]
     pub fn new() -> Self {
-        Self { nodes: Vec::new() }
+        Self::default()
     }
 
     #[must_use]
     pub fn from_vec(vec: Vec<T>) -> Self {
         let mut list = Self {
             nodes: Vec::with_capacity(vec.len()),
+            ..Self::default()
         };
 
         for item in vec {
@@ -35,84 +54,115 @@ impl<T> ArenaLinkedList<T> {
 
     #[must_use]
     pub fn is_empty(&self) -> bool {
-        self.nodes.len() < 1
+        self.len == 0
     }
 
     #[must_use]
     pub fn len(&self) -> usize {
-        self.nodes.len()
+        self.len
     }
 
     #[must_use]
     pub fn head(&self) -> Option<&LinkedListNode<T>> {
-        self.nodes.first()
+        self.node(self.head?)
     }
 
     #[must_use]
     pub fn tail(&self) -> Option<&LinkedListNode<T>> {
-        self.nodes.last()
+        self.node(self.tail?)
+    }
+
+    // Gets the node at the index, or None if it has been removed
+    #[must_use]
+    pub fn node(&self, index: usize) -> Option<&LinkedListNode<T>> {
+        self.nodes.get(index)?.as_ref()
     }
 
     // Gets the first value of the list
     #[must_use]
     pub fn first(&self) -> Option<&T> {
-        match self.nodes.first() {
-            Some(node) => Some(&node.value),
-            None => None,
-        }
+        self.head().map(|node| &node.value)
     }
 
     // Gets the last value of the list
     #[must_use]
     pub fn last(&self) -> Option<&T> {
-        match self.nodes.last() {
-            Some(node) => Some(&node.value),
-            None => None,
-        }
+        self.tail().map(|node| &node.value)
     }
 
     // Gets an immutable reference to a value within the list
     #[must_use]
     pub fn get(&self, index: usize) -> Option<&T> {
-        match self.nodes.get(index) {
-            Some(node) => Some(&node.value),
-            None => None,
-        }
+        self.node(index).map(|node| &node.value)
     }
 
     // Gets a mutable reference to a value within the list
     #[must_use]
     pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
-        match self.nodes.get_mut(index) {
-            Some(node) => Some(&mut node.value),
-            None => None,
-        }
+        Some(&mut self.nodes.get_mut(index)?.as_mut()?.value)
     }
 
     // Pushes a new value to the end of the list, returning the index of the new item
     pub fn push(&mut self, value: T) -> usize {
-        let index = self.nodes.len();
-        self.nodes.push(LinkedListNode {
-            index,
-            value,
-            prev: if index > 0 { Some(index - 1) } else { None },
-            next: None,
-        });
-
-        if index > 0 {
-            self.nodes[index - 1].next.replace(index);
+        let index = self.allocate(value);
+        if let Some(tail) = self.tail {
+            self.link_after(index, tail);
+        } else {
+            self.head = Some(index);
+            self.tail = Some(index);
         }
         index
     }
 
     // Pops the last value off the list, returning it
     pub fn pop(&mut self) -> Option<T> {
-        let node = self.nodes.pop()?;
+        self.remove(self.tail?)
+    }
+
+    // Inserts a new value after the node at the index, returning the index of the new item
+    // (or None if there is no node at the index)
+    pub fn insert_after(&mut self, index: usize, value: T) -> Option<usize> {
+        self.node(index)?;
+
+        let new_index = self.allocate(value);
+        self.link_after(new_index, index);
+        Some(new_index)
+    }
+
+    // Inserts a new value before the node at the index, returning the index of the new item
+    // (or None if there is no node at the index)
+    pub fn insert_before(&mut self, index: usize, value: T) -> Option<usize> {
+        self.node(index)?;
+
+        let new_index = self.allocate(value);
+        self.link_before(new_index, index);
+        Some(new_index)
+    }
+
+    // Removes the node at the index, returning its value (if it existed)
+    // The index will be reused by a later insert
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        self.node(index)?;
 
-        let last = self.nodes.len() - 1;
-        self.nodes[last].next = None;
+        self.unlink(index);
+        self.len -= 1;
+        self.free.push(index);
+
+        self.nodes[index].take().map(|node| node.value)
+    }
 
-        Some(node.value)
+    // Moves the node at the index so that it follows the target node, keeping its index
+    // Returns false (without moving anything) if either node does not exist
+    pub fn move_after(&mut self, index: usize, target: usize) -> bool {
+        if self.node(index).is_none() || self.node(target).is_none() {
+            return false;
+        }
+
+        if index != target {
+            self.unlink(index);
+            self.link_after(index, target);
+        }
+        true
     }
 
     // Traverses the linked list from head to tail
@@ -120,17 +170,11 @@ impl<T> ArenaLinkedList<T> {
     where
         F: FnMut(&T),
     {
-        let Some(mut current) = self.nodes.first() else {
-            return;
-        };
-
-        loop {
-            func(&current.value);
-
-            current = match current.next {
-                Some(index) => &self.nodes[index],
-                None => break,
-            }
+        let mut current = self.head;
+        while let Some(index) = current {
+            let node = self.slot(index);
+            func(&node.value);
+            current = node.next;
         }
     }
 
@@ -139,17 +183,84 @@ impl<T> ArenaLinkedList<T> {
     where
         F: FnMut(&T),
     {
-        let Some(mut current) = self.nodes.last() else {
-            return;
+        let mut current = self.tail;
+        while let Some(index) = current {
+            let node = self.slot(index);
+            func(&node.value);
+            current = node.prev;
+        }
+    }
+
+    // Stores the value in a free slot (reusing a removed one if possible), without linking it
+    fn allocate(&mut self, value: T) -> usize {
+        let index = self.free.pop().unwrap_or(self.nodes.len());
+        let node = LinkedListNode {
+            index,
+            value,
+            prev: None,
+            next: None,
         };
 
-        loop {
-            func(&current.value);
+        if index == self.nodes.len() {
+            self.nodes.push(Some(node));
+        } else {
+            self.nodes[index] = Some(node);
+        }
+
+        self.len += 1;
+        index
+    }
+
+    fn slot(&self, index: usize) -> &LinkedListNode<T> {
+        self.nodes[index].as_ref().unwrap()
+    }
 
-            current = match current.prev {
-                Some(index) => &self.nodes[index],
-                None => break,
-            }
+    fn slot_mut(&mut self, index: usize) -> &mut LinkedListNode<T> {
+        self.nodes[index].as_mut().unwrap()
+    }
+
+    // Links the detached node into the list, directly after the target node
+    fn link_after(&mut self, index: usize, target: usize) {
+        let next = self.slot(target).next;
+
+        let node = self.slot_mut(index);
+        node.prev = Some(target);
+        node.next = next;
+        self.slot_mut(target).next = Some(index);
+
+        match next {
+            Some(next) => self.slot_mut(next).prev = Some(index),
+            None => self.tail = Some(index),
+        }
+    }
+
+    // Links the detached node into the list, directly before the target node
+    fn link_before(&mut self, index: usize, target: usize) {
+        let prev = self.slot(target).prev;
+
+        let node = self.slot_mut(index);
+        node.prev = prev;
+        node.next = Some(target);
+        self.slot_mut(target).prev = Some(index);
+
+        match prev {
+            Some(prev) => self.slot_mut(prev).next = Some(index),
+            None => self.head = Some(index),
+        }
+    }
+
+    // Detaches the node from its neighbors, joining them to each other instead
+    fn unlink(&mut self, index: usize) {
+        let node = self.slot_mut(index);
+        let (prev, next) = (node.prev.take(), node.next.take());
+
+        match prev {
+            Some(prev) => self.slot_mut(prev).next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => self.slot_mut(next).prev = prev,
+            None => self.tail = prev,
         }
     }
 }
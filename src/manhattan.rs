@@ -0,0 +1,206 @@
+use crate::{manhattan_distance, Interval, Point, Rect};
+use std::iter::FusedIterator;
+use std::ops::Range;
+
+// All points within a manhattan distance of the center form a diamond shape
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Diamond {
+    pub center: Point,
+    pub radius: u32,
+}
+
+impl Diamond {
+    #[must_use]
+    pub fn new(center: Point, radius: u32) -> Self {
+        Self { center, radius }
+    }
+
+    // Creates the smallest diamond around the center that still touches the edge point
+    #[must_use]
+    pub fn from_edge(center: Point, edge: Point) -> Self {
+        Self::new(center, distance(center, edge))
+    }
+
+    #[must_use]
+    pub fn contains(&self, point: &Point) -> bool {
+        distance(self.center, *point) <= self.radius
+    }
+
+    // Determines if any point is shared between the two diamonds
+    #[must_use]
+    pub fn intersects(&self, other: &Diamond) -> bool {
+        u64::from(distance(self.center, other.center))
+            <= u64::from(self.radius) + u64::from(other.radius)
+    }
+
+    // Gets the range of X values covered within a single row, or None if it is out of reach
+    #[must_use]
+    pub fn row_intersection(&self, y: i32) -> Option<Interval> {
+        let half_width = self.radius.checked_sub(y.abs_diff(self.center.y))?;
+        Some(clamped_span(self.center.x, half_width))
+    }
+
+    // Gets the range of Y values covered within a single column, or None if it is out of reach
+    #[must_use]
+    pub fn column_intersection(&self, x: i32) -> Option<Interval> {
+        let half_height = self.radius.checked_sub(x.abs_diff(self.center.x))?;
+        Some(clamped_span(self.center.y, half_height))
+    }
+
+    // Gets every point exactly one space outside of the diamond (radius + 1)
+    // Useful for finding the only uncovered point between many overlapping diamonds
+    #[must_use]
+    pub fn perimeter(&self) -> DiamondRing {
+        DiamondRing::new(self.center, u64::from(self.radius) + 1)
+    }
+
+    // Gets every point on the outer edge of the diamond (exactly the radius)
+    #[must_use]
+    pub fn edge(&self) -> DiamondRing {
+        DiamondRing::new(self.center, u64::from(self.radius))
+    }
+
+    // Gets the smallest rectangle that contains the entire diamond
+    #[must_use]
+    pub fn bounds(&self) -> Rect {
+        Rect::from_point(self.center).expand(self.radius)
+    }
+
+    // Gets the diamond in rotated coordinates, where it becomes an axis-aligned square
+    // Intersecting these squares gives the overlap of the original diamonds, while a union
+    // only gives the bounding square of both
+    #[must_use]
+    pub fn rotated_bounds(&self) -> Rect {
+        Rect::from_point(rotate45(self.center)).expand(self.radius)
+    }
+}
+
+// Iterates every point at an exact manhattan distance from the center, clockwise from the east
+// Points beyond the i32 range are skipped, so a ring near the edge may be missing some sides
+#[derive(Debug, Clone)]
+pub struct DiamondRing {
+    center: Point,
+    distance: u64,
+    // The steps along each side (from one corner towards the next) that remain to be visited
+    sides: [Range<u64>; 4],
+    side: usize,
+}
+
+// The offset from the center is (x, y) * distance + (dx, dy) * step along each side
+const RING_SIDES: [(i64, i64, i64, i64); 4] =
+    [(1, 0, -1, 1), (0, 1, -1, -1), (-1, 0, 1, -1), (0, -1, 1, 1)];
+
+impl DiamondRing {
+    #[must_use]
+    pub fn new(center: Point, distance: u64) -> Self {
+        // A ring without any distance is only the center, which is always in range
+        if distance == 0 {
+            return Self {
+                center,
+                distance,
+                sides: [0..1, 0..0, 0..0, 0..0],
+                side: 0,
+            };
+        }
+
+        let d = i64::try_from(distance).unwrap();
+        let sides = RING_SIDES.map(|(x, y, dx, dy)| {
+            let (x_min, x_max) = steps_in_range(center.x, x * d, dx);
+            let (y_min, y_max) = steps_in_range(center.y, y * d, dy);
+            let (min, max) = (x_min.max(y_min).max(0), x_max.min(y_max).min(d - 1));
+            if min <= max {
+                min.unsigned_abs()..max.unsigned_abs() + 1
+            } else {
+                0..0
+            }
+        });
+
+        Self {
+            center,
+            distance,
+            sides,
+            side: 0,
+        }
+    }
+}
+
+impl Iterator for DiamondRing {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(steps) = self.sides.get_mut(self.side) {
+            let Some(step) = steps.next() else {
+                self.side += 1;
+                continue;
+            };
+
+            let (x, y, dx, dy) = RING_SIDES[self.side];
+            let (d, step) = (
+                i64::try_from(self.distance).unwrap(),
+                i64::try_from(step).unwrap(),
+            );
+            let offset = |center: i32, corner: i64, delta: i64| {
+                i32::try_from(i64::from(center) + corner * d + delta * step).unwrap()
+            };
+            return Some(Point::new(
+                offset(self.center.x, x, dx),
+                offset(self.center.y, y, dy),
+            ));
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.sides[self.side.min(4)..]
+            .iter()
+            .map(|steps| steps.end - steps.start)
+            .sum::<u64>();
+        let remaining = usize::try_from(remaining).unwrap();
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for DiamondRing {}
+
+impl FusedIterator for DiamondRing {}
+
+// Gets the (min, max) steps along a side that keep the coordinate within the i32 range,
+// where the coordinate is the center plus the corner offset plus the delta (1 or -1) per step
+fn steps_in_range(center: i32, corner: i64, delta: i64) -> (i64, i64) {
+    let min = i64::from(i32::MIN) - i64::from(center) - corner;
+    let max = i64::from(i32::MAX) - i64::from(center) - corner;
+    if delta > 0 {
+        (min, max)
+    } else {
+        (-max, -min)
+    }
+}
+
+// Gets the values within the distance of the center, clamped to the i32 range
+fn clamped_span(center: i32, distance: u32) -> Interval {
+    let clamp = |value: i64| i32::try_from(value.clamp(i32::MIN.into(), i32::MAX.into())).unwrap();
+    let (center, distance) = (i64::from(center), i64::from(distance));
+    Interval::new(clamp(center - distance), clamp(center + distance))
+}
+
+// Rotates a point by 45 degrees (u = x + y, v = x - y), turning manhattan diamonds into squares
+#[must_use]
+pub fn rotate45(point: Point) -> Point {
+    Point::new(point.x + point.y, point.x - point.y)
+}
+
+// Reverses the 45 degree rotation, or None if the rotated point falls between integer points
+#[must_use]
+pub fn unrotate45(point: Point) -> Option<Point> {
+    if (point.x + point.y) % 2 != 0 {
+        return None;
+    }
+    Some(Point::new(
+        i32::midpoint(point.x, point.y),
+        i32::midpoint(point.x, -point.y),
+    ))
+}
+
+fn distance(a: Point, b: Point) -> u32 {
+    manhattan_distance(a.x, a.y, b.x, b.y)
+}
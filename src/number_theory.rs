@@ -0,0 +1,169 @@
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+
+// Integer types supported by the number theory functions, including `BigInt`
+// Point coordinates build on this too, adding the bounds only the primitive integers meet
+// Values are passed by value, so `BigInt` callers may need to clone
+pub trait Integer:
+    Clone
+    + Ord
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    fn zero() -> Self;
+    fn one() -> Self;
+}
+
+macro_rules! impl_integer {
+    ($($t:ty),*) => {
+        $(
+            impl Integer for $t {
+                fn zero() -> Self {
+                    0
+                }
+
+                fn one() -> Self {
+                    1
+                }
+            }
+        )*
+    };
+}
+
+impl_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+// Signed integers can hold the negative coefficients produced by the extended Euclidean algorithm
+pub trait SignedInteger: Integer + Neg<Output = Self> {}
+
+impl<T> SignedInteger for T where T: Integer + Neg<Output = T> {}
+
+// Gets the greatest common divisor, which is never negative (and zero only if both values are)
+pub fn gcd<T: Integer>(a: T, b: T) -> T {
+    let (mut a, mut b) = (a, b);
+    while b != T::zero() {
+        let remainder = a % b.clone();
+        a = b;
+        b = remainder;
+    }
+
+    if a < T::zero() {
+        T::zero() - a
+    } else {
+        a
+    }
+}
+
+// Gets the least common multiple, the smallest non-negative value divisible by both
+pub fn lcm<T: Integer>(a: T, b: T) -> T {
+    if a == T::zero() || b == T::zero() {
+        return T::zero();
+    }
+
+    let lcm = a.clone() / gcd(a, b.clone()) * b;
+    if lcm < T::zero() {
+        T::zero() - lcm
+    } else {
+        lcm
+    }
+}
+
+// Gets the gcd along with coefficients (x, y) such that a*x + b*y = gcd(a, b)
+pub fn extended_gcd<T: SignedInteger>(a: T, b: T) -> (T, T, T) {
+    // Tracks the coefficients (x, y) of each remainder as it is reduced
+    let (mut old_remainder, mut remainder) = (a, b);
+    let (mut old_x, mut x) = (T::one(), T::zero());
+    let (mut old_y, mut y) = (T::zero(), T::one());
+
+    while remainder != T::zero() {
+        let quotient = old_remainder.clone() / remainder.clone();
+        (old_remainder, remainder) = (
+            remainder.clone(),
+            old_remainder - quotient.clone() * remainder,
+        );
+        (old_x, x) = (x.clone(), old_x - quotient.clone() * x);
+        (old_y, y) = (y.clone(), old_y - quotient * y);
+    }
+
+    // Keep the gcd non-negative, flipping the coefficients to match
+    if old_remainder < T::zero() {
+        (-old_remainder, -old_x, -old_y)
+    } else {
+        (old_remainder, old_x, old_y)
+    }
+}
+
+// Gets the remainder after dividing by the (positive) modulus, which is never negative
+pub fn modulo<T: Integer>(value: T, modulus: T) -> T {
+    let remainder = value % modulus.clone();
+    if remainder < T::zero() {
+        remainder + modulus
+    } else {
+        remainder
+    }
+}
+
+// Raises the base to the (non-negative) exponent, modulo the (positive) modulus
+// Uses square and multiply, so the intermediate products must fit within the type (modulus squared)
+#[allow(clippy::needless_pass_by_value)]
+pub fn pow_mod<T: Integer>(base: T, exponent: T, modulus: T) -> T {
+    assert!(modulus > T::zero(), "Modulus must be positive");
+    assert!(exponent >= T::zero(), "Exponent must not be negative");
+
+    let two = T::one() + T::one();
+    let mut base = modulo(base, modulus.clone());
+    let mut exponent = exponent;
+    let mut result = modulo(T::one(), modulus.clone());
+
+    while exponent > T::zero() {
+        if exponent.clone() % two.clone() == T::one() {
+            result = result * base.clone() % modulus.clone();
+        }
+        base = base.clone() * base % modulus.clone();
+        exponent = exponent / two.clone();
+    }
+
+    result
+}
+
+// Gets the value x such that a*x = 1 (mod modulus), or None if a and the modulus are not coprime
+pub fn mod_inverse<T: SignedInteger>(a: T, modulus: T) -> Option<T> {
+    let (gcd, x, _) = extended_gcd(a, modulus.clone());
+    if gcd == T::one() {
+        Some(modulo(x, modulus))
+    } else {
+        None
+    }
+}
+
+// Solves a system of congruences x = residue (mod modulus), given as (residue, modulus) pairs
+// The moduli do not need to be coprime, but must agree wherever they share factors
+// Returns the smallest non-negative solution and the lcm of the moduli (every solution is
+// congruent to it), or None if the congruences contradict one another
+pub fn chinese_remainder<T: SignedInteger>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    assert!(
+        congruences.iter().all(|(_, modulus)| *modulus > T::zero()),
+        "Moduli must be positive"
+    );
+
+    congruences.iter().try_fold(
+        (T::zero(), T::one()),
+        |(residue, modulus), (next_residue, next_modulus)| {
+            // Merge x = r1 (mod m1) and x = r2 (mod m2) by solving m1*k = r2 - r1 (mod m2)
+            let (gcd, m1_inverse, _) = extended_gcd(modulus.clone(), next_modulus.clone());
+            let difference = next_residue.clone() - residue.clone();
+            if difference.clone() % gcd.clone() != T::zero() {
+                return None;
+            }
+
+            let reduced_modulus = next_modulus.clone() / gcd.clone();
+            let k = modulo(difference / gcd * m1_inverse, reduced_modulus.clone());
+            let combined_modulus = modulus.clone() * reduced_modulus;
+            let combined = modulo(residue + modulus * k, combined_modulus.clone());
+            Some((combined, combined_modulus))
+        },
+    )
+}
@@ -0,0 +1,554 @@
+use crate::{Integer, SignedInteger};
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::hash::Hash;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+// Integer types that can be used as point coordinates, which are the primitive integers
+// (not `BigInt`, since coordinates are copied freely and used as grid indices)
+pub trait Coordinate:
+    Integer + Copy + Hash + Default + Display + TryFrom<usize> + TryInto<usize>
+{
+}
+
+impl<T> Coordinate for T where
+    T: Integer + Copy + Hash + Default + Display + TryFrom<usize> + TryInto<usize>
+{
+}
+
+// Signed coordinates can move in any direction, so they support stepping and neighbors
+pub trait SignedCoordinate: Coordinate + SignedInteger + From<i8> {
+    #[must_use]
+    fn signum(self) -> Self {
+        match self.cmp(&Self::zero()) {
+            std::cmp::Ordering::Less => -Self::one(),
+            std::cmp::Ordering::Equal => Self::zero(),
+            std::cmp::Ordering::Greater => Self::one(),
+        }
+    }
+}
+
+impl<T> SignedCoordinate for T where T: Coordinate + SignedInteger + From<i8> {}
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Point<T = i32> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T>
+where
+    T: Coordinate,
+{
+    #[must_use]
+    pub fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    // Attempts to convert grid (column, row) coordinates to a point, or None if out of range
+    #[must_use]
+    pub fn from_grid(x: usize, y: usize) -> Option<Self> {
+        Some(Self::new(T::try_from(x).ok()?, T::try_from(y).ok()?))
+    }
+
+    // Attempts to convert the point to grid (column, row) coordinates, or None if negative
+    #[must_use]
+    pub fn to_grid(&self) -> Option<(usize, usize)> {
+        Some((self.x.try_into().ok()?, self.y.try_into().ok()?))
+    }
+
+    // Attempts to convert each coordinate to another integer type, or None if out of range
+    #[must_use]
+    pub fn try_cast<U>(&self) -> Option<Point<U>>
+    where
+        U: Coordinate + TryFrom<T>,
+    {
+        Some(Point::new(
+            U::try_from(self.x).ok()?,
+            U::try_from(self.y).ok()?,
+        ))
+    }
+
+    // Converts each coordinate to a wider integer type, which can never fail
+    #[must_use]
+    pub fn cast<U>(&self) -> Point<U>
+    where
+        U: Coordinate + From<T>,
+    {
+        Point::new(U::from(self.x), U::from(self.y))
+    }
+}
+
+impl<T> Point<T>
+where
+    T: SignedCoordinate,
+{
+    // Gets the point offset one space in the direction
+    #[must_use]
+    pub fn step(&self, direction: Direction) -> Self {
+        let (dx, dy) = direction.delta();
+        Self::new(self.x + T::from(dx), self.y + T::from(dy))
+    }
+
+    // Gets the sign of each axis, useful for moving one space towards another point
+    #[must_use]
+    pub fn signum(&self) -> Self {
+        Self::new(self.x.signum(), self.y.signum())
+    }
+
+    // Gets the four adjacent points (north, east, south, west)
+    #[must_use]
+    pub fn neighbors4(&self) -> [Self; 4] {
+        Direction::CARDINALS.map(|dir| self.step(dir))
+    }
+
+    // Gets the eight adjacent points, including diagonals (clockwise from north)
+    #[must_use]
+    pub fn neighbors8(&self) -> [Self; 8] {
+        Direction::ALL.map(|dir| self.step(dir))
+    }
+}
+
+impl<T> Display for Point<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}, {}", self.x, self.y)
+    }
+}
+
+// Parses a point from the common puzzle notations: `x,y`, `(x, y)` and `x=..., y=...`
+impl<T> FromStr for Point<T>
+where
+    T: Coordinate + FromStr,
+{
+    type Err = PointParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+
+        // Parentheses are optional, but must be balanced
+        let inner = if let Some(inner) = trimmed.strip_prefix('(').and_then(|t| t.strip_suffix(')'))
+        {
+            inner
+        } else if trimmed.starts_with('(') || trimmed.ends_with(')') {
+            return Err(PointParseError::UnbalancedParentheses(s.to_string()));
+        } else {
+            trimmed
+        };
+
+        let Some((first, second)) = inner.split_once(',') else {
+            return Err(PointParseError::MissingSeparator(s.to_string()));
+        };
+
+        // Each component may optionally be labeled, such as `x=-2`
+        let first = parse_component::<T>(first)?;
+        let second = parse_component::<T>(second)?;
+
+        match (first, second) {
+            ((None, x), (None, y))
+            | ((Some('x'), x), (Some('y'), y))
+            | ((Some('y'), y), (Some('x'), x)) => Ok(Self::new(x, y)),
+            _ => Err(PointParseError::InvalidLabels(s.to_string())),
+        }
+    }
+}
+
+// Parses a single coordinate, returning the label (if any) and the value
+fn parse_component<T>(component: &str) -> Result<(Option<char>, T), PointParseError>
+where
+    T: FromStr,
+{
+    let (label, value) = match component.split_once('=') {
+        Some((label, value)) => {
+            let label = match label.trim() {
+                "x" | "X" => 'x',
+                "y" | "Y" => 'y',
+                _ => return Err(PointParseError::InvalidLabels(component.trim().to_string())),
+            };
+            (Some(label), value.trim())
+        }
+        None => (None, component.trim()),
+    };
+
+    match value.parse::<T>() {
+        Ok(value) => Ok((label, value)),
+        Err(_) => Err(PointParseError::InvalidCoordinate(value.to_string())),
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum PointParseError {
+    MissingSeparator(String),
+    UnbalancedParentheses(String),
+    InvalidLabels(String),
+    InvalidCoordinate(String),
+}
+
+impl Display for PointParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PointParseError::MissingSeparator(s) => {
+                write!(f, "Missing comma between coordinates: {s:?}")
+            }
+            PointParseError::UnbalancedParentheses(s) => {
+                write!(f, "Unbalanced parentheses around point: {s:?}")
+            }
+            PointParseError::InvalidLabels(s) => {
+                write!(
+                    f,
+                    "Coordinates must be labeled `x=` and `y=` (or not at all): {s:?}"
+                )
+            }
+            PointParseError::InvalidCoordinate(s) => write!(f, "Invalid coordinate value: {s:?}"),
+        }
+    }
+}
+
+impl Error for PointParseError {}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T> From<Point<T>> for (T, T) {
+    fn from(point: Point<T>) -> Self {
+        (point.x, point.y)
+    }
+}
+
+impl<T> Add for Point<T>
+where
+    T: Add<Output = T>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+        }
+    }
+}
+
+impl<T> AddAssign for Point<T>
+where
+    T: Copy + Add<Output = T>,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T> Sub for Point<T>
+where
+    T: Sub<Output = T>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+        }
+    }
+}
+
+impl<T> SubAssign for Point<T>
+where
+    T: Copy + Sub<Output = T>,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T> Neg for Point<T>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl<T> Mul<T> for Point<T>
+where
+    T: Copy + Mul<Output = T>,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self {
+            x: self.x * rhs,
+            y: self.y * rhs,
+        }
+    }
+}
+
+// The 3D equivalent of a point, for puzzles that work in cubes instead of tiles
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Point3<T = i32> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point3<T>
+where
+    T: Coordinate,
+{
+    #[must_use]
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    // Attempts to convert grid (x, y, z) coordinates to a point, or None if out of range
+    #[must_use]
+    pub fn from_grid(x: usize, y: usize, z: usize) -> Option<Self> {
+        Some(Self::new(
+            T::try_from(x).ok()?,
+            T::try_from(y).ok()?,
+            T::try_from(z).ok()?,
+        ))
+    }
+
+    // Attempts to convert the point to grid (x, y, z) coordinates, or None if negative
+    #[must_use]
+    pub fn to_grid(&self) -> Option<(usize, usize, usize)> {
+        Some((
+            self.x.try_into().ok()?,
+            self.y.try_into().ok()?,
+            self.z.try_into().ok()?,
+        ))
+    }
+}
+
+impl<T> Point3<T>
+where
+    T: SignedCoordinate,
+{
+    // Gets the six points that share a face with this one
+    #[must_use]
+    pub fn neighbors6(&self) -> [Self; 6] {
+        let one = T::one();
+        let zero = T::zero();
+        [
+            (-one, zero, zero),
+            (one, zero, zero),
+            (zero, -one, zero),
+            (zero, one, zero),
+            (zero, zero, -one),
+            (zero, zero, one),
+        ]
+        .map(|(dx, dy, dz)| Self::new(self.x + dx, self.y + dy, self.z + dz))
+    }
+}
+
+impl<T> Display for Point3<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}, {}, {}", self.x, self.y, self.z)
+    }
+}
+
+impl<T> From<(T, T, T)> for Point3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T> From<Point3<T>> for (T, T, T) {
+    fn from(point: Point3<T>) -> Self {
+        (point.x, point.y, point.z)
+    }
+}
+
+impl<T> Add for Point3<T>
+where
+    T: Add<Output = T>,
+{
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x + rhs.x,
+            y: self.y + rhs.y,
+            z: self.z + rhs.z,
+        }
+    }
+}
+
+impl<T> AddAssign for Point3<T>
+where
+    T: Copy + Add<Output = T>,
+{
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<T> Sub for Point3<T>
+where
+    T: Sub<Output = T>,
+{
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self {
+            x: self.x - rhs.x,
+            y: self.y - rhs.y,
+            z: self.z - rhs.z,
+        }
+    }
+}
+
+impl<T> SubAssign for Point3<T>
+where
+    T: Copy + Sub<Output = T>,
+{
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<T> Neg for Point3<T>
+where
+    T: Neg<Output = T>,
+{
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+impl<T> Mul<T> for Point3<T>
+where
+    T: Copy + Mul<Output = T>,
+{
+    type Output = Self;
+
+    fn mul(self, rhs: T) -> Self::Output {
+        Self {
+            x: self.x * rhs,
+            y: self.y * rhs,
+            z: self.z * rhs,
+        }
+    }
+}
+
+// Represents each cardinal and diagonal direction, where north is towards negative Y
+// This matches the grid types, where row zero is the top of the grid
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum Direction {
+    North,
+    Northeast,
+    East,
+    Southeast,
+    South,
+    Southwest,
+    West,
+    Northwest,
+}
+
+impl Direction {
+    pub const CARDINALS: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    pub const DIAGONALS: [Direction; 4] = [
+        Direction::Northeast,
+        Direction::Southeast,
+        Direction::Southwest,
+        Direction::Northwest,
+    ];
+
+    // All directions in clockwise order, starting from north
+    pub const ALL: [Direction; 8] = [
+        Direction::North,
+        Direction::Northeast,
+        Direction::East,
+        Direction::Southeast,
+        Direction::South,
+        Direction::Southwest,
+        Direction::West,
+        Direction::Northwest,
+    ];
+
+    // Gets the direction matching a unit offset, or None if it is not a single step
+    #[must_use]
+    pub fn from_offset(offset: Point) -> Option<Self> {
+        Self::ALL.into_iter().find(|dir| dir.offset() == offset)
+    }
+
+    // Gets the x/y offset for moving one space in this direction
+    #[must_use]
+    pub fn offset(&self) -> Point {
+        let (dx, dy) = self.delta();
+        Point::new(i32::from(dx), i32::from(dy))
+    }
+
+    // Gets the raw x/y offset, small enough to convert into any signed coordinate
+    fn delta(self) -> (i8, i8) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::Northeast => (1, -1),
+            Direction::East => (1, 0),
+            Direction::Southeast => (1, 1),
+            Direction::South => (0, 1),
+            Direction::Southwest => (-1, 1),
+            Direction::West => (-1, 0),
+            Direction::Northwest => (-1, -1),
+        }
+    }
+
+    #[must_use]
+    pub fn is_diagonal(&self) -> bool {
+        Self::DIAGONALS.contains(self)
+    }
+
+    // Rotates 90 degrees counter-clockwise
+    #[must_use]
+    pub fn turn_left(&self) -> Self {
+        self.rotate(-2)
+    }
+
+    // Rotates 90 degrees clockwise
+    #[must_use]
+    pub fn turn_right(&self) -> Self {
+        self.rotate(2)
+    }
+
+    // Rotates 180 degrees
+    #[must_use]
+    pub fn opposite(&self) -> Self {
+        self.rotate(4)
+    }
+
+    // Rotates by a number of 45 degree steps, where positive is clockwise
+    #[must_use]
+    pub fn rotate(&self, steps: i32) -> Self {
+        let index = Self::ALL.iter().position(|dir| dir == self).unwrap();
+        let rotated = (i32::try_from(index).unwrap() + steps).rem_euclid(8);
+        Self::ALL[usize::try_from(rotated).unwrap()]
+    }
+}
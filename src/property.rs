@@ -0,0 +1,423 @@
+use std::fmt::{Debug, Display, Formatter};
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+// Small, fast pseudo-random generator (SplitMix64), so property checks need no external crates
+// The same seed always produces the same sequence, which makes failures reproducible
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    pub fn next_u128(&mut self) -> u128 {
+        (u128::from(self.next_u64()) << 64) | u128::from(self.next_u64())
+    }
+
+    pub fn next_bool(&mut self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+
+    // Gets a value from 0 up to (but not including) the bound, which must not be zero
+    // The modulo bias is negligible for the small bounds used when generating test cases
+    pub fn below(&mut self, bound: u128) -> u128 {
+        assert!(bound > 0, "Bound must be positive");
+        self.next_u128() % bound
+    }
+
+    // Gets an index into a collection of the given length, which must not be zero
+    pub fn index(&mut self, len: usize) -> usize {
+        usize::try_from(self.below(len as u128)).unwrap()
+    }
+}
+
+// Describes how to generate random values, and how to shrink a failing value into simpler ones
+pub trait Strategy {
+    type Value: Clone + Debug;
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value;
+
+    // Gets simpler variations of the value, with the simplest first
+    fn shrink(&self, value: &Self::Value) -> Vec<Self::Value>;
+}
+
+// Generates integers within an inclusive range, favoring the edges of the range
+// Shrinks towards zero, or whichever end of the range is closest to it
+#[derive(Debug, Copy, Clone)]
+pub struct IntStrategy<T> {
+    pub min: T,
+    pub max: T,
+}
+
+impl<T> IntStrategy<T>
+where
+    T: Ord + Copy,
+{
+    #[must_use]
+    pub fn new(a: T, b: T) -> Self {
+        Self {
+            min: a.min(b),
+            max: a.max(b),
+        }
+    }
+}
+
+macro_rules! impl_int_strategy {
+    ($($t:ty),*) => {
+        $(
+            impl Strategy for IntStrategy<$t> {
+                type Value = $t;
+
+                // Offsets are calculated with wrapping u128 arithmetic, which works for any range
+                #[allow(
+                    clippy::cast_possible_truncation,
+                    clippy::cast_possible_wrap,
+                    clippy::cast_sign_loss,
+                    clippy::cast_lossless
+                )]
+                fn generate(&self, rng: &mut Rng) -> $t {
+                    if rng.below(8) == 0 {
+                        let edges = [self.min, self.max, self.shrink_target()];
+                        return edges[rng.index(edges.len())];
+                    }
+
+                    // Pick a random number of bits first, so small values are just as likely as large
+                    let span = (self.max as u128).wrapping_sub(self.min as u128);
+                    let offset = rng.next_u128() >> rng.below(128);
+                    let offset = offset.checked_rem(span.wrapping_add(1)).unwrap_or(offset);
+                    (self.min as u128).wrapping_add(offset) as $t
+                }
+
+                fn shrink(&self, &value: &$t) -> Vec<$t> {
+                    let target = self.shrink_target();
+                    if value == target {
+                        return Vec::new();
+                    }
+
+                    // Try the target, then move closer to it by halving the distance each time
+                    let mut candidates = vec![target];
+                    let mut distance = (value - target) / 2;
+                    while distance != 0 {
+                        candidates.push(value - distance);
+                        distance /= 2;
+                    }
+
+                    let step = if value > target { value - 1 } else { value + 1 };
+                    if !candidates.contains(&step) {
+                        candidates.push(step);
+                    }
+                    candidates
+                }
+            }
+
+            impl IntStrategy<$t> {
+                #[allow(unused_comparisons)]
+                fn shrink_target(&self) -> $t {
+                    if self.min > 0 {
+                        self.min
+                    } else if self.max < 0 {
+                        self.max
+                    } else {
+                        0
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_int_strategy!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+// Generates vectors of up to the maximum length, shrinking by removing and then simplifying elements
+#[derive(Debug, Copy, Clone)]
+pub struct VecStrategy<S> {
+    pub element: S,
+    pub max_len: usize,
+}
+
+impl<S> VecStrategy<S> {
+    #[must_use]
+    pub fn new(element: S, max_len: usize) -> Self {
+        Self { element, max_len }
+    }
+}
+
+impl<S> Strategy for VecStrategy<S>
+where
+    S: Strategy,
+{
+    type Value = Vec<S::Value>;
+
+    fn generate(&self, rng: &mut Rng) -> Self::Value {
+        let len = rng.index(self.max_len + 1);
+        (0..len).map(|_| self.element.generate(rng)).collect()
+    }
+
+    fn shrink(&self, value: &Self::Value) -> Vec<Self::Value> {
+        let mut candidates = shrink_len(value);
+
+        for (index, element) in value.iter().enumerate() {
+            for simpler in self.element.shrink(element) {
+                let mut candidate = value.clone();
+                candidate[index] = simpler;
+                candidates.push(candidate);
+            }
+        }
+
+        candidates
+    }
+}
+
+// Generates strings of up to the maximum length, using only the characters of the alphabet
+// Shrinks by removing characters, then replacing them with the first character of the alphabet
+#[derive(Debug, Clone)]
+pub struct StringStrategy {
+    pub alphabet: Vec<char>,
+    pub max_len: usize,
+}
+
+impl StringStrategy {
+    #[must_use]
+    pub fn new(alphabet: &str, max_len: usize) -> Self {
+        assert!(!alphabet.is_empty(), "Alphabet must not be empty");
+        Self {
+            alphabet: alphabet.chars().collect(),
+            max_len,
+        }
+    }
+}
+
+impl Strategy for StringStrategy {
+    type Value = String;
+
+    fn generate(&self, rng: &mut Rng) -> String {
+        let len = rng.index(self.max_len + 1);
+        (0..len)
+            .map(|_| self.alphabet[rng.index(self.alphabet.len())])
+            .collect()
+    }
+
+    fn shrink(&self, value: &String) -> Vec<String> {
+        let chars: Vec<char> = value.chars().collect();
+        let simplest = self.alphabet[0];
+
+        let mut candidates: Vec<String> = shrink_len(&chars)
+            .into_iter()
+            .map(|chars| chars.into_iter().collect())
+            .collect();
+
+        for (index, &c) in chars.iter().enumerate() {
+            if c != simplest {
+                let mut candidate = chars.clone();
+                candidate[index] = simplest;
+                candidates.push(candidate.into_iter().collect());
+            }
+        }
+
+        candidates
+    }
+}
+
+// Gets shorter versions of the items: empty, each half, then with each single item removed
+fn shrink_len<T: Clone>(items: &[T]) -> Vec<Vec<T>> {
+    if items.is_empty() {
+        return Vec::new();
+    }
+
+    let mut candidates = vec![Vec::new()];
+    if items.len() > 2 {
+        let half = items.len() / 2;
+        candidates.push(items[..half].to_vec());
+        candidates.push(items[half..].to_vec());
+    }
+    if items.len() > 1 {
+        for index in 0..items.len() {
+            let mut candidate = items.to_vec();
+            candidate.remove(index);
+            candidates.push(candidate);
+        }
+    }
+    candidates
+}
+
+// Tuples of strategies generate each value independently, and shrink one value at a time
+macro_rules! impl_tuple_strategy {
+    ($(($($name:ident $index:tt),*)),*) => {
+        $(
+            impl<$($name),*> Strategy for ($($name,)*)
+            where
+                $($name: Strategy),*
+            {
+                type Value = ($($name::Value,)*);
+
+                fn generate(&self, rng: &mut Rng) -> Self::Value {
+                    ($(self.$index.generate(rng),)*)
+                }
+
+                fn shrink(&self, value: &Self::Value) -> Vec<Self::Value> {
+                    let mut candidates = Vec::new();
+                    $(
+                        for simpler in self.$index.shrink(&value.$index) {
+                            let mut candidate = value.clone();
+                            candidate.$index = simpler;
+                            candidates.push(candidate);
+                        }
+                    )*
+                    candidates
+                }
+            }
+        )*
+    };
+}
+
+impl_tuple_strategy!((A 0, B 1), (A 0, B 1, C 2));
+
+// Controls how many random cases are checked, and how hard to try shrinking a failure
+#[derive(Debug, Copy, Clone)]
+pub struct PropertyConfig {
+    pub cases: usize,
+    pub seed: u64,
+    pub max_shrink_steps: usize,
+}
+
+impl Default for PropertyConfig {
+    fn default() -> Self {
+        Self {
+            cases: 256,
+            seed: 0x5eed_1e55_c0ff_ee00,
+            max_shrink_steps: 1000,
+        }
+    }
+}
+
+// Describes a failing case, along with the simplest failing value found by shrinking it
+#[derive(Debug, Clone)]
+pub struct PropertyFailure<V> {
+    pub seed: u64,
+    pub case: usize,
+    pub original: V,
+    pub shrunk: V,
+    pub shrink_steps: usize,
+    // The panic message, if the property panicked rather than returning false
+    pub message: Option<String>,
+}
+
+impl<V> Display for PropertyFailure<V>
+where
+    V: Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "Property failed on case {} (seed {:#x}), shrunk {} times",
+            self.case, self.seed, self.shrink_steps
+        )?;
+        writeln!(f, "  original: {:?}", self.original)?;
+        write!(f, "  shrunk:   {:?}", self.shrunk)?;
+        if let Some(message) = &self.message {
+            write!(f, "\n  message:  {message}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<V> std::error::Error for PropertyFailure<V> where V: Debug {}
+
+// Checks the property holds for randomly generated values, where returning false or panicking
+// is a failure; any failing value is shrunk to the simplest value that still fails
+pub fn check_property<S, F>(
+    config: &PropertyConfig,
+    strategy: &S,
+    property: F,
+) -> Result<(), PropertyFailure<S::Value>>
+where
+    S: Strategy,
+    F: Fn(&S::Value) -> bool,
+{
+    // Silence the panic messages while checking, since shrinking may panic many times
+    let hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|_| {}));
+    let result = run_cases(config, strategy, &property);
+    std::panic::set_hook(hook);
+    result
+}
+
+fn run_cases<S, F>(
+    config: &PropertyConfig,
+    strategy: &S,
+    property: &F,
+) -> Result<(), PropertyFailure<S::Value>>
+where
+    S: Strategy,
+    F: Fn(&S::Value) -> bool,
+{
+    let mut rng = Rng::new(config.seed);
+
+    for case in 0..config.cases {
+        let value = strategy.generate(&mut rng);
+        let Err(message) = run_property(property, &value) else {
+            continue;
+        };
+
+        // Keep replacing the value with the first simpler value that still fails
+        let mut failure = PropertyFailure {
+            seed: config.seed,
+            case,
+            original: value.clone(),
+            shrunk: value,
+            shrink_steps: 0,
+            message,
+        };
+        while failure.shrink_steps < config.max_shrink_steps {
+            let simpler = strategy
+                .shrink(&failure.shrunk)
+                .into_iter()
+                .find_map(|candidate| {
+                    run_property(property, &candidate)
+                        .err()
+                        .map(|message| (candidate, message))
+                });
+
+            let Some((candidate, message)) = simpler else {
+                break;
+            };
+            failure.shrunk = candidate;
+            failure.message = message;
+            failure.shrink_steps += 1;
+        }
+
+        return Err(failure);
+    }
+
+    Ok(())
+}
+
+// Runs the property against a single value, returning the panic message (if any) when it fails
+fn run_property<V, F>(property: &F, value: &V) -> Result<(), Option<String>>
+where
+    F: Fn(&V) -> bool,
+{
+    match catch_unwind(AssertUnwindSafe(|| property(value))) {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(None),
+        Err(payload) => Err(Some(
+            payload
+                .downcast_ref::<String>()
+                .cloned()
+                .or_else(|| payload.downcast_ref::<&str>().map(ToString::to_string))
+                .unwrap_or_default(),
+        )),
+    }
+}
@@ -0,0 +1,233 @@
+use crate::bigint::{forward_binary_op, from_primitive};
+use crate::{gcd, BigInt, BigIntError};
+use std::cmp::Ordering;
+use std::fmt::{Display, Formatter};
+use std::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+// An exact fraction, always stored in lowest terms with a positive denominator
+// For example, 2/4 and -3/-6 are both stored as 1/2
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Rational {
+    numerator: BigInt,
+    denominator: BigInt,
+}
+
+impl Rational {
+    // Creates the fraction, reducing it to lowest terms
+    #[must_use]
+    pub fn new(numerator: BigInt, denominator: BigInt) -> Self {
+        Self::checked_new(numerator, denominator)
+            .unwrap_or_else(|| panic!("attempt to create a fraction with a zero denominator"))
+    }
+
+    // Creates the fraction, reducing it to lowest terms (or None if the denominator is zero)
+    #[must_use]
+    pub fn checked_new(numerator: BigInt, denominator: BigInt) -> Option<Self> {
+        if denominator.is_zero() {
+            return None;
+        }
+
+        let divisor = gcd(numerator.clone(), denominator.clone());
+        let (numerator, denominator) = (numerator / &divisor, denominator / &divisor);
+
+        // The sign always belongs to the numerator
+        Some(if denominator.is_negative() {
+            Self {
+                numerator: -numerator,
+                denominator: -denominator,
+            }
+        } else {
+            Self {
+                numerator,
+                denominator,
+            }
+        })
+    }
+
+    #[must_use]
+    pub fn from_integer(value: BigInt) -> Self {
+        Self {
+            numerator: value,
+            denominator: BigInt::from(1u8),
+        }
+    }
+
+    #[must_use]
+    pub fn numerator(&self) -> &BigInt {
+        &self.numerator
+    }
+
+    #[must_use]
+    pub fn denominator(&self) -> &BigInt {
+        &self.denominator
+    }
+
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        self.numerator.is_zero()
+    }
+
+    #[must_use]
+    pub fn is_negative(&self) -> bool {
+        self.numerator.is_negative()
+    }
+
+    #[must_use]
+    pub fn is_integer(&self) -> bool {
+        self.denominator == BigInt::from(1u8)
+    }
+
+    // Gets the value as an integer, or None if it is not a whole number
+    #[must_use]
+    pub fn to_integer(&self) -> Option<BigInt> {
+        if self.is_integer() {
+            Some(self.numerator.clone())
+        } else {
+            None
+        }
+    }
+
+    #[must_use]
+    pub fn abs(&self) -> Self {
+        Self {
+            numerator: self.numerator.abs(),
+            denominator: self.denominator.clone(),
+        }
+    }
+
+    // Gets the reciprocal (one divided by the value), or None if the value is zero
+    #[must_use]
+    pub fn recip(&self) -> Option<Self> {
+        Self::checked_new(self.denominator.clone(), self.numerator.clone())
+    }
+
+    #[must_use]
+    pub fn add(&self, other: &Self) -> Self {
+        Self::new(
+            &self.numerator * &other.denominator + &other.numerator * &self.denominator,
+            &self.denominator * &other.denominator,
+        )
+    }
+
+    #[must_use]
+    pub fn subtract(&self, other: &Self) -> Self {
+        Self::new(
+            &self.numerator * &other.denominator - &other.numerator * &self.denominator,
+            &self.denominator * &other.denominator,
+        )
+    }
+
+    #[must_use]
+    pub fn multiply_by(&self, other: &Self) -> Self {
+        Self::new(
+            &self.numerator * &other.numerator,
+            &self.denominator * &other.denominator,
+        )
+    }
+
+    #[must_use]
+    pub fn divide_by(&self, other: &Self) -> Self {
+        self.checked_div(other)
+            .unwrap_or_else(|| panic!("attempt to divide by zero"))
+    }
+
+    // Divides by the other value, or None if dividing by zero
+    #[must_use]
+    pub fn checked_div(&self, other: &Self) -> Option<Self> {
+        Self::checked_new(
+            &self.numerator * &other.denominator,
+            &self.denominator * &other.numerator,
+        )
+    }
+}
+
+impl Default for Rational {
+    fn default() -> Self {
+        Self::from_integer(BigInt::default())
+    }
+}
+
+impl From<BigInt> for Rational {
+    fn from(value: BigInt) -> Self {
+        Self::from_integer(value)
+    }
+}
+
+from_primitive!(
+    Rational, from_integer(BigInt): u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+impl Neg for Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        Rational {
+            numerator: -self.numerator,
+            denominator: self.denominator,
+        }
+    }
+}
+
+impl Neg for &Rational {
+    type Output = Rational;
+
+    fn neg(self) -> Rational {
+        -self.clone()
+    }
+}
+
+forward_binary_op!(Rational, Add, add, AddAssign, add_assign, add);
+forward_binary_op!(Rational, Sub, sub, SubAssign, sub_assign, subtract);
+forward_binary_op!(Rational, Mul, mul, MulAssign, mul_assign, multiply_by);
+forward_binary_op!(Rational, Div, div, DivAssign, div_assign, divide_by);
+
+impl Ord for Rational {
+    // Both denominators are positive, so cross-multiplying keeps the order
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.numerator * &other.denominator).cmp(&(&other.numerator * &self.denominator))
+    }
+}
+
+impl PartialOrd for Rational {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Display for Rational {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.is_integer() {
+            write!(f, "{}", self.numerator)
+        } else {
+            write!(f, "{}/{}", self.numerator, self.denominator)
+        }
+    }
+}
+
+impl FromStr for Rational {
+    type Err = BigIntError;
+
+    // Parses either an integer or a fraction such as "-3/4", which does not need to be reduced
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Some((numerator, denominator)) = s.split_once('/') else {
+            return Ok(Self::from_integer(s.parse()?));
+        };
+
+        // Positions within the denominator are reported relative to the whole string
+        let offset = numerator.len() + 1;
+        let numerator = numerator.parse()?;
+        let denominator = denominator.parse().map_err(|error| match error {
+            BigIntError::InvalidDigit {
+                character,
+                position,
+            } => BigIntError::InvalidDigit {
+                character,
+                position: position + offset,
+            },
+            error => error,
+        })?;
+
+        Self::checked_new(numerator, denominator).ok_or(BigIntError::DivisionByZero)
+    }
+}
@@ -0,0 +1,135 @@
+use crate::Point;
+use std::borrow::Borrow;
+use std::fmt::{Display, Formatter};
+
+// Axis-aligned rectangle where both the min and max corners are inclusive
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Rect {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Rect {
+    // Creates the rectangle spanning both corners, regardless of which order they are given
+    #[must_use]
+    pub fn new(a: Point, b: Point) -> Self {
+        Self {
+            min: Point::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Point::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    // Creates a rectangle covering a single point
+    #[must_use]
+    pub fn from_point(point: Point) -> Self {
+        Self {
+            min: point,
+            max: point,
+        }
+    }
+
+    // A rectangle spanning every i32 is one wider than u32 can hold
+    #[must_use]
+    pub fn width(&self) -> u64 {
+        u64::from(self.max.x.abs_diff(self.min.x)) + 1
+    }
+
+    #[must_use]
+    pub fn height(&self) -> u64 {
+        u64::from(self.max.y.abs_diff(self.min.y)) + 1
+    }
+
+    // Gets the number of points within the rectangle
+    #[must_use]
+    pub fn area(&self) -> u128 {
+        u128::from(self.width()) * u128::from(self.height())
+    }
+
+    #[must_use]
+    pub fn contains(&self, point: &Point) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    // Determines if the other rectangle is entirely within this one
+    #[must_use]
+    pub fn contains_rect(&self, other: &Rect) -> bool {
+        self.contains(&other.min) && self.contains(&other.max)
+    }
+
+    #[must_use]
+    pub fn intersects(&self, other: &Rect) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    // Gets the area shared by both rectangles, or None if they do not overlap
+    #[must_use]
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let min = Point::new(self.min.x.max(other.min.x), self.min.y.max(other.min.y));
+        let max = Point::new(self.max.x.min(other.max.x), self.max.y.min(other.max.y));
+
+        if min.x <= max.x && min.y <= max.y {
+            Some(Rect { min, max })
+        } else {
+            None
+        }
+    }
+
+    // Gets the smallest rectangle that contains both rectangles
+    #[must_use]
+    pub fn union(&self, other: &Rect) -> Rect {
+        Rect {
+            min: Point::new(self.min.x.min(other.min.x), self.min.y.min(other.min.y)),
+            max: Point::new(self.max.x.max(other.max.x), self.max.y.max(other.max.y)),
+        }
+    }
+
+    // Gets the smallest rectangle that contains both this rectangle and the point
+    #[must_use]
+    pub fn include(&self, point: Point) -> Rect {
+        self.union(&Rect::from_point(point))
+    }
+
+    // Grows the rectangle by the margin on every side, stopping at the edges of the i32 range
+    #[must_use]
+    pub fn expand(&self, margin: u32) -> Rect {
+        let grow = |value: i32, margin: i64| {
+            i32::try_from((i64::from(value) + margin).clamp(i32::MIN.into(), i32::MAX.into()))
+                .unwrap()
+        };
+        let margin = i64::from(margin);
+        Rect {
+            min: Point::new(grow(self.min.x, -margin), grow(self.min.y, -margin)),
+            max: Point::new(grow(self.max.x, margin), grow(self.max.y, margin)),
+        }
+    }
+
+    // Iterates every point within the rectangle, row by row
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let Rect { min, max } = *self;
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point::new(x, y)))
+    }
+}
+
+impl Display for Rect {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}) -> ({})", self.min, self.max)
+    }
+}
+
+// Allows any iterator of points to determine the rectangle that bounds them all
+pub trait BoundingBox {
+    // Gets the smallest rectangle containing every point, or None if there are no points
+    fn bounding_box(self) -> Option<Rect>;
+}
+
+impl<I> BoundingBox for I
+where
+    I: IntoIterator,
+    I::Item: Borrow<Point>,
+{
+    fn bounding_box(self) -> Option<Rect> {
+        self.into_iter()
+            .map(|point| Rect::from_point(*point.borrow()))
+            .reduce(|bounds, rect| bounds.union(&rect))
+    }
+}
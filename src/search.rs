@@ -0,0 +1,192 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// Optimization puzzles (robot blueprints, valve opening, etc) are maximization problems
+// over a huge tree of states, which A* cannot express since there is no single goal.
+// Implement this trait to describe the problem, then run it with either search below.
+pub trait SearchProblem {
+    type State: Clone;
+    type Score: Ord + Copy;
+    type Key: Eq + Hash;
+
+    // Gets all of the states reachable from this state (empty if terminal)
+    fn successors(&self, state: &Self::State) -> Vec<Self::State>;
+
+    // Gets the score that has been achieved by this state
+    fn score(&self, state: &Self::State) -> Self::Score;
+
+    // Gets the best score that could possibly be achieved from this state (must never underestimate)
+    fn upper_bound(&self, state: &Self::State) -> Self::Score;
+
+    // Gets the key used to memoize visited states, or None to disable memoization
+    fn key(&self, _state: &Self::State) -> Option<Self::Key> {
+        None
+    }
+
+    // Gets the priority used to rank states within a beam, defaulting to the score
+    fn priority(&self, state: &Self::State) -> Self::Score {
+        self.score(state)
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct SearchStats {
+    pub nodes_explored: usize,
+    pub bound_prunes: usize,
+    pub memo_prunes: usize,
+    pub beam_prunes: usize,
+}
+
+impl SearchStats {
+    // Gets the total number of prunes triggered, regardless of reason
+    #[must_use]
+    pub fn total_prunes(&self) -> usize {
+        self.bound_prunes + self.memo_prunes + self.beam_prunes
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SearchResult<S, V> {
+    pub best: Option<(S, V)>,
+    pub stats: SearchStats,
+}
+
+impl<S, V> SearchResult<S, V>
+where
+    V: Copy,
+{
+    // Gets the best score found (if any)
+    #[must_use]
+    pub fn best_score(&self) -> Option<V> {
+        self.best.as_ref().map(|(_, score)| *score)
+    }
+
+    // Gets the state that achieved the best score (if any)
+    #[must_use]
+    pub fn best_state(&self) -> Option<&S> {
+        self.best.as_ref().map(|(state, _)| state)
+    }
+}
+
+// Implements a depth-first branch-and-bound search, pruning any state whose upper bound
+// cannot beat the best score found so far, and skipping states already seen with a better score
+pub fn branch_and_bound<P>(problem: &P, start: P::State) -> SearchResult<P::State, P::Score>
+where
+    P: SearchProblem,
+{
+    let mut stats = SearchStats::default();
+    let mut best: Option<(P::State, P::Score)> = None;
+    let mut memo: HashMap<P::Key, P::Score> = HashMap::new();
+    let mut stack = vec![start];
+
+    while let Some(state) = stack.pop() {
+        // The best score may have improved since this state was pushed, so check the bound now
+        if let Some((_, best_score)) = &best {
+            if problem.upper_bound(&state) <= *best_score {
+                stats.bound_prunes += 1;
+                continue;
+            }
+        }
+
+        stats.nodes_explored += 1;
+
+        let score = problem.score(&state);
+        if best
+            .as_ref()
+            .is_none_or(|(_, best_score)| score > *best_score)
+        {
+            best = Some((state.clone(), score));
+        }
+
+        // Push in reverse so the first successor is explored first
+        let mut successors = problem.successors(&state);
+        successors.reverse();
+
+        for next in successors {
+            if is_memoized(problem, &mut memo, &next) {
+                stats.memo_prunes += 1;
+                continue;
+            }
+            stack.push(next);
+        }
+    }
+
+    SearchResult { best, stats }
+}
+
+// Implements a beam search, only keeping the best `width` states (by priority) at each depth
+pub fn beam_search<P>(
+    problem: &P,
+    start: P::State,
+    width: usize,
+) -> SearchResult<P::State, P::Score>
+where
+    P: SearchProblem,
+{
+    let mut stats = SearchStats::default();
+    let mut best: Option<(P::State, P::Score)> = None;
+    let mut memo: HashMap<P::Key, P::Score> = HashMap::new();
+    let mut beam = vec![start];
+
+    while !beam.is_empty() {
+        let mut candidates = Vec::with_capacity(beam.len() * 2);
+
+        for state in beam {
+            stats.nodes_explored += 1;
+
+            let score = problem.score(&state);
+            if best
+                .as_ref()
+                .is_none_or(|(_, best_score)| score > *best_score)
+            {
+                best = Some((state.clone(), score));
+            }
+
+            for next in problem.successors(&state) {
+                if is_memoized(problem, &mut memo, &next) {
+                    stats.memo_prunes += 1;
+                    continue;
+                }
+                candidates.push(next);
+            }
+        }
+
+        // Drop any candidates that cannot possibly beat the best score found so far
+        if let Some((_, best_score)) = &best {
+            let before = candidates.len();
+            candidates.retain(|state| problem.upper_bound(state) > *best_score);
+            stats.bound_prunes += before - candidates.len();
+        }
+
+        // Keep only the highest priority candidates for the next depth
+        if candidates.len() > width {
+            candidates.sort_by_key(|state| std::cmp::Reverse(problem.priority(state)));
+            stats.beam_prunes += candidates.len() - width;
+            candidates.truncate(width);
+        }
+
+        beam = candidates;
+    }
+
+    SearchResult { best, stats }
+}
+
+// Determines if the state has already been seen with an equal or better score,
+// otherwise records the state's score for future lookups
+fn is_memoized<P>(problem: &P, memo: &mut HashMap<P::Key, P::Score>, state: &P::State) -> bool
+where
+    P: SearchProblem,
+{
+    let Some(key) = problem.key(state) else {
+        return false;
+    };
+
+    let score = problem.score(state);
+    match memo.get(&key) {
+        Some(&seen) if seen >= score => true,
+        _ => {
+            memo.insert(key, score);
+            false
+        }
+    }
+}